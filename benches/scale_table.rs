@@ -0,0 +1,40 @@
+//! Compares abbreviating a million integers with divisors computed per call against
+//! divisors looked up in a [ScaleTable] built once.
+//!
+//! Run with `cargo bench --bench scale_table`.
+
+use abbrev_num::{abbrev, format_with_table, Options, ScaleTable};
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u64 = 1_000_000;
+
+fn main() {
+    let options = Options::default();
+    let table = ScaleTable::new(&options).unwrap();
+
+    let start = Instant::now();
+    for number in 0..ITERATIONS {
+        black_box(abbrev(black_box(number * 7_919_113), Some(options)));
+    }
+    let computed = start.elapsed();
+
+    let start = Instant::now();
+    for number in 0..ITERATIONS {
+        black_box(format_with_table(
+            black_box(number * 7_919_113),
+            Some(options),
+            &table,
+        ));
+    }
+    let looked_up = start.elapsed();
+
+    println!(
+        "computed divisors: {:?} per call",
+        computed / ITERATIONS as u32
+    );
+    println!(
+        "table divisors: {:?} per call",
+        looked_up / ITERATIONS as u32
+    );
+}
@@ -0,0 +1,22 @@
+//! Measures formatting with the default unit tables, including the first call, which paid
+//! for initializing the tables while they were lazily initialized.
+//!
+//! Run with `cargo bench --bench unit_tables`.
+
+use abbrev_num::abbrev_num;
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u32 = 1_000_000;
+
+fn main() {
+    let start = Instant::now();
+    black_box(abbrev_num(black_box(1_500_000), None));
+    println!("first call: {:?}", start.elapsed());
+
+    let start = Instant::now();
+    for number in 0..ITERATIONS {
+        black_box(abbrev_num(black_box(number as isize * 1_000), None));
+    }
+    println!("steady state: {:?} per call", start.elapsed() / ITERATIONS);
+}
@@ -0,0 +1,323 @@
+use crate::format::{Mantissa, Parts};
+use crate::{
+    base, carry, fitted_parts, fixed_level, fixed_parts, level_options, magnitude_parts, round,
+    signed_options, unit, AbbrevError, Decimal, NotationMode, Options,
+};
+use alloc::string::{String, ToString};
+use num_bigint::{BigInt, BigUint, Sign};
+
+/// The number of fractional digits kept when a big integer is scaled down to a mantissa.
+const FRACTION_DIGITS: u32 = 18;
+
+/// Abbreviates the given big integer into a human-friendly format according to
+/// specified options.
+///
+/// This behaves exactly like [abbrev_num](crate::abbrev_num) for magnitudes of any
+/// size. Numbers beyond the last abbreviation unit can be written in scientific notation
+/// with [Options::scientific_fallback]. Magnitudes beyond `u128` cannot be written in
+/// [NotationMode::Standard].
+///
+/// # Arguments
+///
+/// * `number` - The big integer to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated form of the number.
+/// Returns `None` if the number cannot be abbreviated using the provided abbreviations or
+/// notation.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev_bigint;
+/// use num_bigint::BigInt;
+///
+/// let number = BigInt::from(10).pow(24) * -3;
+///
+/// assert_eq!(abbrev_bigint(&number, None), Some("-3Y".to_string()));
+/// ```
+pub fn abbrev_bigint(number: &BigInt, options: Option<Options>) -> Option<String> {
+    let options = options.unwrap_or_default();
+    let (options, parts) = fitted_parts(&options, |options| bigint_parts(number, options)).ok()?;
+
+    Some(parts.to_string(&options))
+}
+
+/// Resolves the parts of an abbreviated big integer.
+fn bigint_parts<'a>(number: &BigInt, options: &Options<'a>) -> Result<Parts<'a>, AbbrevError> {
+    let negative = number.sign() == Sign::Minus;
+    let magnitude = number.magnitude();
+
+    if let Ok(absolute) = u128::try_from(magnitude) {
+        return magnitude_parts(negative, absolute, options, "");
+    }
+
+    // Any maximum magnitude or cap fits in 128 bits, so it is always exceeded here.
+    if options.max_magnitude.is_some() {
+        return Err(AbbrevError::AboveMaxMagnitude);
+    }
+
+    if options.saturate_at.is_some() {
+        return magnitude_parts(negative, u128::MAX, options, "");
+    }
+
+    // Like standard notation, numbers at face value must fit in their mantissa.
+    if options.assume_unit.is_some() {
+        return Err(AbbrevError::ConversionFailed);
+    }
+
+    // The magnitude is beyond any base and threshold here, so it is always abbreviated.
+    let options = &signed_options(options, negative);
+
+    match options.notation {
+        NotationMode::Compact => {}
+        NotationMode::Standard => return Err(AbbrevError::ConversionFailed),
+        NotationMode::Scientific => return scientific_parts(negative, magnitude, 1, options),
+        NotationMode::Engineering => return scientific_parts(negative, magnitude, 3, options),
+    }
+
+    let base = BigUint::from(base(options)?);
+
+    if let Some(level) = fixed_level(options)? {
+        let mantissa = ratio(magnitude, &base.pow(level))?;
+
+        return fixed_parts(negative, mantissa, level, options, "");
+    }
+
+    let mut level = 0;
+    let mut divisor = BigUint::from(1_u8);
+
+    while &(&divisor * &base) <= magnitude {
+        divisor *= &base;
+        level += 1;
+    }
+
+    let result = round(ratio(magnitude, &divisor)?, &level_options(level, options));
+    let (result, level) = carry(result, level, options)?;
+
+    let unit = match unit(level, &Mantissa::Decimal(result), options) {
+        Err(AbbrevError::MagnitudeOutOfRange { .. }) if options.scientific_fallback => {
+            return scientific_parts(negative, magnitude, 1, options);
+        }
+        unit => unit?,
+    };
+
+    Ok(Parts {
+        negative,
+        mantissa: Mantissa::Decimal(result.normalize()),
+        unit,
+        unit_suffix: "",
+        exponent: None,
+        divisor: u128::try_from(base.pow(level)).unwrap_or(u128::MAX),
+        precision: level_options(level, options).precision,
+        saturated: false,
+        spaced_suffix: false,
+        level,
+        places: None,
+    })
+}
+
+/// Resolves the parts of a non-zero big integer written with a power of ten that is a
+/// multiple of the given step.
+fn scientific_parts<'a>(
+    negative: bool,
+    magnitude: &BigUint,
+    step: u32,
+    options: &Options<'a>,
+) -> Result<Parts<'a>, AbbrevError> {
+    let exponent = (magnitude.to_string().len() as u32 - 1) / step * step;
+    let limit = Decimal::from(10_u128.pow(step));
+    let mut result = round(
+        ratio(magnitude, &BigUint::from(10_u8).pow(exponent))?,
+        options,
+    );
+    let mut exponent = i32::try_from(exponent).map_err(|_| AbbrevError::ConversionFailed)?;
+
+    if result >= limit {
+        result = round(result / limit, options);
+        exponent += step as i32;
+    }
+
+    Ok(Parts {
+        negative,
+        mantissa: Mantissa::Decimal(result.normalize()),
+        unit: "",
+        unit_suffix: "",
+        exponent: Some(exponent),
+        divisor: 10_u128.saturating_pow(exponent as u32),
+        precision: None,
+        saturated: false,
+        spaced_suffix: false,
+        level: 0,
+        places: None,
+    })
+}
+
+/// Returns the quotient of the magnitude and the divisor as a decimal, keeping as many
+/// fractional digits as fit next to the integer part.
+fn ratio(magnitude: &BigUint, divisor: &BigUint) -> Result<Decimal, AbbrevError> {
+    let quotient = magnitude / divisor;
+    let remainder = magnitude % divisor;
+    let digits = quotient.to_string().len() as u32;
+    let scale = FRACTION_DIGITS.min(28_u32.saturating_sub(digits));
+    let scaled = quotient * BigUint::from(10_u8).pow(scale)
+        + remainder * BigUint::from(10_u8).pow(scale) / divisor;
+    let scaled = i128::try_from(scaled).map_err(|_| AbbrevError::ConversionFailed)?;
+
+    Decimal::try_from_i128_with_scale(scaled, scale).map_err(|_| AbbrevError::ConversionFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abbrev_i128;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn can_abbreviate_big_integers() {
+        let fixtures: Vec<i128> = vec![
+            0,
+            -999,
+            1_500,
+            -2_345_678,
+            999_999_999,
+            1_000_000_000_000_000_000_000_000,
+            999_999_999_999_999_999_999_999_999,
+        ];
+
+        fixtures.iter().for_each(|case| {
+            let result = abbrev_bigint(&BigInt::from(*case), None);
+            assert_eq!(result, abbrev_i128(*case, None));
+        });
+
+        let options = Options {
+            base: Some(1024),
+            precision: Some(2),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_bigint(&BigInt::from(1_536), Some(options)),
+            abbrev_i128(1_536, Some(options))
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_big_integers_using_long_unit_lists() {
+        let units: Vec<String> = (0..40).map(|level| format!("_c{level}")).collect();
+        let units: Vec<&str> = units.iter().map(String::as_str).collect();
+        let options = Options {
+            abbreviations: Some(&units),
+            ..Default::default()
+        };
+        let number = BigInt::from(15) * BigInt::from(10).pow(98);
+
+        assert_eq!(
+            abbrev_bigint(&number, Some(options)),
+            Some("1.5_c33".to_string())
+        );
+        assert_eq!(
+            abbrev_bigint(&-number, Some(options)),
+            Some("-1.5_c33".to_string())
+        );
+    }
+
+    #[test]
+    fn can_fall_back_to_scientific_notation() {
+        let number: BigInt = "1234567890".repeat(10).parse().unwrap();
+
+        assert_eq!(number.to_string().len(), 100);
+        assert_eq!(abbrev_bigint(&number, None), None);
+
+        let options = Options {
+            scientific_fallback: true,
+            precision: Some(3),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_bigint(&number, Some(options)),
+            Some("1.235e99".to_string())
+        );
+        assert_eq!(
+            abbrev_bigint(&(BigInt::from(10).pow(30) - 1), Some(options)),
+            Some("1e30".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_big_integers_using_notation_modes() {
+        let number = BigInt::from(15) * BigInt::from(10).pow(40);
+        let fixtures: Vec<(NotationMode, Option<&str>)> = vec![
+            (NotationMode::Compact, None),
+            (NotationMode::Standard, None),
+            (NotationMode::Scientific, Some("1.5e41")),
+            (NotationMode::Engineering, Some("150e39")),
+        ];
+
+        fixtures.iter().for_each(|(notation, expected)| {
+            let options = Options {
+                notation: *notation,
+                ..Default::default()
+            };
+            let result = abbrev_bigint(&number, Some(options));
+            assert_eq!(result, expected.map(|value| value.to_string()));
+        });
+
+        let options = Options {
+            notation: NotationMode::Standard,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_bigint(&BigInt::from(-1_500_000), Some(options)),
+            Some("-1500000".to_string())
+        );
+    }
+
+    #[test]
+    fn cannot_abbreviate_big_integers_above_max_magnitude() {
+        let options = Options {
+            max_magnitude: Some(u128::MAX),
+            scientific_fallback: true,
+            ..Default::default()
+        };
+        let number = BigInt::from(u128::MAX);
+
+        assert_eq!(
+            abbrev_bigint(&number, Some(options)),
+            Some("3.4e38".to_string())
+        );
+        assert_eq!(abbrev_bigint(&(number + 1), Some(options)), None);
+    }
+
+    #[test]
+    fn can_abbreviate_saturated_big_integers() {
+        let options = Options {
+            saturate_at: Some(1_000_000),
+            ..Default::default()
+        };
+        let number = -BigInt::from(10).pow(50);
+
+        assert_eq!(
+            abbrev_bigint(&number, Some(options)),
+            Some("-1M+".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_big_integers_using_fixed_unit() {
+        let options = Options {
+            fixed_unit: Some("Y"),
+            ..Default::default()
+        };
+        let number = BigInt::from(25) * BigInt::from(10).pow(40);
+
+        assert_eq!(
+            abbrev_bigint(&number, Some(options)),
+            Some("250000000000000000Y".to_string())
+        );
+    }
+}
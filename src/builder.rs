@@ -0,0 +1,414 @@
+use crate::{
+    Align, Decimal, Grouping, Locale, NegativeStyle, Normalization, NotationMode, NumberScale,
+    Options, Rounding, RoundingStrategy, ScaleTable, SignPosition,
+};
+
+/// A builder for [Options], created with [Options::builder].
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{abbrev_num, Options, RoundingStrategy};
+///
+/// let options = Options::builder()
+///     .precision(0)
+///     .rounding_strategy(RoundingStrategy::ToZero)
+///     .build();
+///
+/// assert_eq!(abbrev_num(1_566_450, Some(options)), Some("1M".to_string()));
+/// ```
+#[derive(Debug, Default, Copy, Clone)]
+pub struct OptionsBuilder<'a> {
+    options: Options<'a>,
+}
+
+impl<'a> Options<'a> {
+    /// Returns a builder for the options, starting from the defaults.
+    pub fn builder() -> OptionsBuilder<'a> {
+        OptionsBuilder::default()
+    }
+}
+
+impl<'a> OptionsBuilder<'a> {
+    /// Sets [Options::precision].
+    pub fn precision(mut self, precision: u32) -> Self {
+        self.options.precision = Some(precision);
+        self
+    }
+
+    /// Sets [Options::min_precision].
+    pub fn min_precision(mut self, min_precision: u32) -> Self {
+        self.options.min_precision = Some(min_precision);
+        self
+    }
+
+    /// Sets [Options::abbreviations].
+    pub fn abbreviations(mut self, abbreviations: &'a [&'a str]) -> Self {
+        self.options.abbreviations = Some(abbreviations);
+        self
+    }
+
+    /// Sets [Options::rounding_strategy].
+    pub fn rounding_strategy(mut self, rounding_strategy: RoundingStrategy) -> Self {
+        self.options.rounding_strategy = Some(rounding_strategy);
+        self
+    }
+
+    /// Sets the rounding strategies for the given [Rounding], as in
+    /// [Options::with_rounding].
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.options = self.options.with_rounding(rounding);
+        self
+    }
+
+    /// Sets [Options::small_units].
+    pub fn small_units(mut self, small_units: &'a [&'a str]) -> Self {
+        self.options.small_units = Some(small_units);
+        self
+    }
+
+    /// Sets [Options::base].
+    pub fn base(mut self, base: u32) -> Self {
+        self.options.base = Some(base);
+        self
+    }
+
+    /// Sets [Options::step].
+    pub fn step(mut self, step: u32) -> Self {
+        self.options.step = Some(step);
+        self
+    }
+
+    /// Sets [Options::space].
+    pub fn space(mut self, space: bool) -> Self {
+        self.options.space = space;
+        self
+    }
+
+    /// Sets [Options::long_words].
+    pub fn long_words(mut self, long_words: bool) -> Self {
+        self.options.long_words = long_words;
+        self
+    }
+
+    /// Sets [Options::decimal_separator].
+    pub fn decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.options.decimal_separator = Some(decimal_separator);
+        self
+    }
+
+    /// Sets [Options::group_separator].
+    pub fn group_separator(mut self, group_separator: char) -> Self {
+        self.options.group_separator = Some(group_separator);
+        self
+    }
+
+    /// Sets [Options::min_abbrev_value].
+    pub fn min_abbrev_value(mut self, min_abbrev_value: usize) -> Self {
+        self.options.min_abbrev_value = Some(min_abbrev_value);
+        self
+    }
+
+    /// Sets [Options::prefix].
+    pub fn prefix(mut self, prefix: &'a str) -> Self {
+        self.options.prefix = Some(prefix);
+        self
+    }
+
+    /// Sets [Options::suffix].
+    pub fn suffix(mut self, suffix: &'a str) -> Self {
+        self.options.suffix = Some(suffix);
+        self
+    }
+
+    /// Sets [Options::sign_position].
+    pub fn sign_position(mut self, sign_position: SignPosition) -> Self {
+        self.options.sign_position = sign_position;
+        self
+    }
+
+    /// Sets [Options::scientific_fallback].
+    pub fn scientific_fallback(mut self, scientific_fallback: bool) -> Self {
+        self.options.scientific_fallback = scientific_fallback;
+        self
+    }
+
+    /// Sets [Options::width].
+    pub fn width(mut self, width: usize) -> Self {
+        self.options.width = Some(width);
+        self
+    }
+
+    /// Sets [Options::fill].
+    pub fn fill(mut self, fill: char) -> Self {
+        self.options.fill = Some(fill);
+        self
+    }
+
+    /// Sets [Options::align].
+    pub fn align(mut self, align: Align) -> Self {
+        self.options.align = align;
+        self
+    }
+
+    /// Sets [Options::notation].
+    pub fn notation(mut self, notation: NotationMode) -> Self {
+        self.options.notation = notation;
+        self
+    }
+
+    /// Sets [Options::negative_rounding_strategy].
+    pub fn negative_rounding_strategy(
+        mut self,
+        negative_rounding_strategy: RoundingStrategy,
+    ) -> Self {
+        self.options.negative_rounding_strategy = Some(negative_rounding_strategy);
+        self
+    }
+
+    /// Sets [Options::unit_separator].
+    pub fn unit_separator(mut self, unit_separator: &'a str) -> Self {
+        self.options.unit_separator = Some(unit_separator);
+        self
+    }
+
+    /// Sets [Options::negative_style].
+    pub fn negative_style(mut self, negative_style: NegativeStyle) -> Self {
+        self.options.negative_style = negative_style;
+        self
+    }
+
+    /// Sets [Options::superscript_exponent].
+    pub fn superscript_exponent(mut self, superscript_exponent: bool) -> Self {
+        self.options.superscript_exponent = superscript_exponent;
+        self
+    }
+
+    /// Sets [Options::significant_digits].
+    pub fn significant_digits(mut self, significant_digits: u32) -> Self {
+        self.options.significant_digits = Some(significant_digits);
+        self
+    }
+
+    /// Sets [Options::keep_trailing_zeros].
+    pub fn keep_trailing_zeros(mut self, keep_trailing_zeros: bool) -> Self {
+        self.options.keep_trailing_zeros = keep_trailing_zeros;
+        self
+    }
+
+    /// Sets [Options::zero_text].
+    pub fn zero_text(mut self, zero_text: &'a str) -> Self {
+        self.options.zero_text = Some(zero_text);
+        self
+    }
+
+    /// Sets [Options::long_words_plural].
+    pub fn long_words_plural(mut self, long_words_plural: &'a [&'a str]) -> Self {
+        self.options.long_words_plural = Some(long_words_plural);
+        self
+    }
+
+    /// Sets [Options::unit_scale].
+    pub fn unit_scale(mut self, unit_scale: &'a [(u128, &'a str)]) -> Self {
+        self.options.unit_scale = Some(unit_scale);
+        self
+    }
+
+    /// Sets [Options::scale].
+    pub fn scale(mut self, scale: NumberScale) -> Self {
+        self.options.scale = scale;
+        self
+    }
+
+    /// Sets [Options::fixed_unit].
+    pub fn fixed_unit(mut self, fixed_unit: &'a str) -> Self {
+        self.options.fixed_unit = Some(fixed_unit);
+        self
+    }
+
+    /// Sets [Options::max_width].
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.options.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets [Options::hide_zero_exponent].
+    pub fn hide_zero_exponent(mut self, hide_zero_exponent: bool) -> Self {
+        self.options.hide_zero_exponent = hide_zero_exponent;
+        self
+    }
+
+    /// Sets [Options::force_sign].
+    pub fn force_sign(mut self, force_sign: bool) -> Self {
+        self.options.force_sign = force_sign;
+        self
+    }
+
+    /// Sets [Options::force_sign_zero].
+    pub fn force_sign_zero(mut self, force_sign_zero: bool) -> Self {
+        self.options.force_sign_zero = force_sign_zero;
+        self
+    }
+
+    /// Sets [Options::max_precision].
+    pub fn max_precision(mut self, max_precision: u32) -> Self {
+        self.options.max_precision = Some(max_precision);
+        self
+    }
+
+    /// Sets [Options::clamp_to_max_unit].
+    pub fn clamp_to_max_unit(mut self, clamp_to_max_unit: bool) -> Self {
+        self.options.clamp_to_max_unit = clamp_to_max_unit;
+        self
+    }
+
+    /// Sets [Options::precision_by_level].
+    pub fn precision_by_level(mut self, precision_by_level: &'a [u32]) -> Self {
+        self.options.precision_by_level = Some(precision_by_level);
+        self
+    }
+
+    /// Sets [Options::long_bit_rate].
+    pub fn long_bit_rate(mut self, long_bit_rate: bool) -> Self {
+        self.options.long_bit_rate = long_bit_rate;
+        self
+    }
+
+    /// Sets [Options::always_decimal].
+    pub fn always_decimal(mut self, always_decimal: bool) -> Self {
+        self.options.always_decimal = always_decimal;
+        self
+    }
+
+    /// Sets [Options::grouping].
+    pub fn grouping(mut self, grouping: Grouping) -> Self {
+        self.options.grouping = grouping;
+        self
+    }
+
+    /// Sets the separators, grouping and units of the given [Locale], as in
+    /// [Options::with_locale].
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.options = self.options.with_locale(locale);
+        self
+    }
+
+    /// Sets [Options::group_fraction].
+    pub fn group_fraction(mut self, group_fraction: bool) -> Self {
+        self.options.group_fraction = group_fraction;
+        self
+    }
+
+    /// Sets [Options::max_magnitude].
+    pub fn max_magnitude(mut self, max_magnitude: u128) -> Self {
+        self.options.max_magnitude = Some(max_magnitude);
+        self
+    }
+
+    /// Sets [Options::scale_table].
+    pub fn scale_table(mut self, scale_table: &'a ScaleTable) -> Self {
+        self.options.scale_table = Some(scale_table);
+        self
+    }
+
+    /// Sets [Options::nice_numbers].
+    pub fn nice_numbers(mut self, nice_numbers: bool) -> Self {
+        self.options.nice_numbers = nice_numbers;
+        self
+    }
+
+    /// Sets [Options::mantissa_width].
+    pub fn mantissa_width(mut self, mantissa_width: usize) -> Self {
+        self.options.mantissa_width = Some(mantissa_width);
+        self
+    }
+
+    /// Sets [Options::normalization].
+    pub fn normalization(mut self, normalization: Normalization) -> Self {
+        self.options.normalization = normalization;
+        self
+    }
+
+    /// Sets [Options::saturate_at].
+    pub fn saturate_at(mut self, saturate_at: u128) -> Self {
+        self.options.saturate_at = Some(saturate_at);
+        self
+    }
+
+    /// Sets [Options::saturate_suffix].
+    pub fn saturate_suffix(mut self, saturate_suffix: &'a str) -> Self {
+        self.options.saturate_suffix = Some(saturate_suffix);
+        self
+    }
+
+    /// Sets [Options::base_level_offset].
+    pub fn base_level_offset(mut self, base_level_offset: i32) -> Self {
+        self.options.base_level_offset = Some(base_level_offset);
+        self
+    }
+
+    /// Sets [Options::round_threshold].
+    pub fn round_threshold(mut self, round_threshold: Decimal) -> Self {
+        self.options.round_threshold = Some(round_threshold);
+        self
+    }
+
+    /// Sets [Options::unit_before].
+    pub fn unit_before(mut self, unit_before: bool) -> Self {
+        self.options.unit_before = unit_before;
+        self
+    }
+
+    /// Sets [Options::no_unit_promotion].
+    pub fn no_unit_promotion(mut self, no_unit_promotion: bool) -> Self {
+        self.options.no_unit_promotion = no_unit_promotion;
+        self
+    }
+
+    /// Sets [Options::assume_unit].
+    pub fn assume_unit(mut self, assume_unit: &'a str) -> Self {
+        self.options.assume_unit = Some(assume_unit);
+        self
+    }
+
+    /// Sets [Options::clamp_precision].
+    pub fn clamp_precision(mut self, clamp_precision: bool) -> Self {
+        self.options.clamp_precision = clamp_precision;
+        self
+    }
+
+    /// Returns the built options.
+    pub fn build(self) -> Options<'a> {
+        self.options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_build_options() {
+        let units = ["", "k", "M"];
+        let options = Options::builder()
+            .precision(3)
+            .abbreviations(&units)
+            .rounding_strategy(RoundingStrategy::ToZero)
+            .space(true)
+            .decimal_separator(',')
+            .build();
+
+        assert_eq!(
+            options,
+            Options {
+                precision: Some(3),
+                abbreviations: Some(&units),
+                rounding_strategy: Some(RoundingStrategy::ToZero),
+                space: true,
+                decimal_separator: Some(','),
+                ..Default::default()
+            }
+        );
+        assert_eq!(Options::builder().build(), Options::default());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::format::write_fill;
+use crate::{fitted_parts, integer_parts, Options};
+use core::fmt;
+
+/// A number that is abbreviated when displayed, without allocating an intermediate
+/// string.
+///
+/// The displayed value is exactly what [abbrev_num](crate::abbrev_num) returns for the
+/// same inputs. Nothing is written if the number cannot be abbreviated. Width, fill and
+/// alignment flags are supported as well, in which case the result is padded like a
+/// string, but never truncated by a precision flag.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::Abbreviated;
+///
+/// assert_eq!(format!("{}", Abbreviated::new(1_500, None)), "1.5k");
+/// assert_eq!(format!("[{:>6}]", Abbreviated::new(-1_500, None)), "[ -1.5k]");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Abbreviated<'a> {
+    number: isize,
+    options: Options<'a>,
+}
+
+impl<'a> Abbreviated<'a> {
+    /// Creates a displayable abbreviation of the given number.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The integer to be abbreviated.
+    /// * `options` - An optional parameter specifying the [Options] for abbreviation.
+    pub fn new(number: isize, options: Option<Options<'a>>) -> Self {
+        Abbreviated {
+            number,
+            options: options.unwrap_or_default(),
+        }
+    }
+}
+
+impl fmt::Display for Abbreviated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Ok((options, parts)) = fitted_parts(&self.options, |options| {
+            integer_parts(self.number, options, "")
+        }) else {
+            return Ok(());
+        };
+
+        let Some(width) = f.width() else {
+            return parts.write(f, &options);
+        };
+
+        // The result is measured first, so that padding does not need a buffer.
+        let padding = width.saturating_sub(parts.padded_width(&options));
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Left) | None => (0, padding),
+        };
+        let fill = f.fill();
+
+        write_fill(f, fill, before)?;
+        parts.write(f, &options)?;
+        write_fill(f, fill, after)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abbrev_num;
+
+    #[test]
+    fn can_display_abbreviated_numbers() {
+        let options = Options {
+            precision: Some(2),
+            space: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<isize> = vec![0, 150, -1_500, 1_234_567, isize::MAX];
+
+        fixtures.iter().for_each(|case| {
+            let result = Abbreviated::new(*case, Some(options)).to_string();
+            assert_eq!(Some(result), abbrev_num(*case, Some(options)));
+        });
+
+        let options = Options {
+            abbreviations: Some(&["", "k"]),
+            ..Default::default()
+        };
+
+        assert_eq!(Abbreviated::new(1_000_000, Some(options)).to_string(), "");
+    }
+
+    #[test]
+    fn can_display_with_padding() {
+        let value = Abbreviated::new(1_500, None);
+
+        assert_eq!(format!("{value:>6}"), "  1.5k");
+        assert_eq!(format!("{value:<6}|"), "1.5k  |");
+        assert_eq!(format!("{value:*^8}"), "**1.5k**");
+        assert_eq!(format!("{value:*^7}"), "*1.5k**");
+        assert_eq!(format!("{value:6}|"), "1.5k  |");
+        assert_eq!(format!("{value:3}"), "1.5k");
+    }
+
+    #[test]
+    fn can_display_with_precision_flag_without_truncating() {
+        let value = Abbreviated::new(-1_500, None);
+
+        assert_eq!(format!("{value:7.2}"), "-1.5k  ");
+        assert_eq!(format!("{value:>7.1}"), "  -1.5k");
+        assert_eq!(format!("{value:.1}"), "-1.5k");
+
+        let options = Options {
+            width: Some(7),
+            fill: Some('0'),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            format!("[{:>9.1}]", Abbreviated::new(1_500, Some(options))),
+            "[  0001.5k]"
+        );
+    }
+}
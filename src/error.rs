@@ -0,0 +1,102 @@
+use core::fmt;
+
+/// The error returned when a number cannot be abbreviated.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AbbrevError {
+    /// There is no abbreviation unit for the level of the number. The level is negative
+    /// for values below one.
+    MagnitudeOutOfRange {
+        /// The level of the number.
+        level: i32,
+    },
+    /// The number could not be converted to or scaled as a decimal.
+    ConversionFailed,
+    /// The base between abbreviation units is less than `2` or does not fit in 128 bits.
+    InvalidBase,
+    /// The number is `NaN` or infinite.
+    NotFinite,
+    /// The fixed unit is not one of the abbreviation units.
+    UnknownUnit,
+    /// The sink returned an error while the abbreviation was written into it.
+    WriteFailed,
+    /// The number is above [Options::max_magnitude](crate::Options::max_magnitude).
+    AboveMaxMagnitude,
+    /// The abbreviation unit of a level above `0` is empty.
+    EmptyUnit {
+        /// The level of the unit.
+        level: u32,
+    },
+    /// The abbreviation unit of a level is the same as that of a lower level.
+    DuplicateUnit {
+        /// The level of the repeated unit.
+        level: u32,
+    },
+    /// The precision is above the `28` decimal places a decimal can hold.
+    InvalidPrecision {
+        /// The requested precision.
+        precision: u32,
+    },
+}
+
+impl fmt::Display for AbbrevError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbbrevError::MagnitudeOutOfRange { level } => {
+                write!(f, "no abbreviation unit for level {level}")
+            }
+            AbbrevError::ConversionFailed => write!(f, "number could not be converted"),
+            AbbrevError::InvalidBase => write!(f, "base must be at least 2"),
+            AbbrevError::NotFinite => write!(f, "number is not finite"),
+            AbbrevError::UnknownUnit => write!(f, "fixed unit is not an abbreviation unit"),
+            AbbrevError::WriteFailed => write!(f, "abbreviation could not be written"),
+            AbbrevError::AboveMaxMagnitude => write!(f, "number is above the maximum magnitude"),
+            AbbrevError::EmptyUnit { level } => {
+                write!(f, "empty abbreviation unit at level {level}")
+            }
+            AbbrevError::DuplicateUnit { level } => {
+                write!(f, "duplicate abbreviation unit at level {level}")
+            }
+            AbbrevError::InvalidPrecision { precision } => {
+                write!(f, "precision {precision} is above 28 decimal places")
+            }
+        }
+    }
+}
+
+impl core::error::Error for AbbrevError {}
+
+/// The error returned when a format spec cannot be parsed into [Options](crate::Options).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SpecError<'a> {
+    /// The entry does not name any option.
+    UnknownKey {
+        /// The key of the entry.
+        key: &'a str,
+    },
+    /// The value of the entry is not valid for its option, or is missing.
+    InvalidValue {
+        /// The key of the entry.
+        key: &'a str,
+        /// The value of the entry, which is empty when missing.
+        value: &'a str,
+    },
+}
+
+impl fmt::Display for SpecError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpecError::UnknownKey { key } => write!(f, "unknown format option `{key}`"),
+            SpecError::InvalidValue { key, value } => {
+                write!(f, "invalid value `{value}` for format option `{key}`")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SpecError<'_> {}
+
+impl From<fmt::Error> for AbbrevError {
+    fn from(_: fmt::Error) -> Self {
+        AbbrevError::WriteFailed
+    }
+}
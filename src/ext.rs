@@ -0,0 +1,63 @@
+use crate::{abbrev, Integer, Options};
+use alloc::string::String;
+
+/// An extension trait to abbreviate integers with a method call, implemented for all of
+/// the standard integer primitives.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{AbbrevExt, Options};
+///
+/// let options = Options {
+///     precision: Some(2),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(1_500.abbrev(), Some("1.5k".to_string()));
+/// assert_eq!(1_234_567_u64.abbrev_with(options), Some("1.23M".to_string()));
+/// ```
+pub trait AbbrevExt: Integer {
+    /// Abbreviates the number using the default options, like
+    /// [abbrev_num](crate::abbrev_num).
+    fn abbrev(self) -> Option<String> {
+        abbrev(self, None)
+    }
+
+    /// Abbreviates the number according to the specified options, like
+    /// [abbrev_num](crate::abbrev_num).
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The [Options] for abbreviation.
+    fn abbrev_with(self, options: Options) -> Option<String> {
+        abbrev(self, Some(options))
+    }
+}
+
+impl<T: Integer> AbbrevExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abbrev_num;
+
+    #[test]
+    fn can_abbreviate_using_methods() {
+        let options = Options {
+            precision: Some(2),
+            space: true,
+            ..Default::default()
+        };
+
+        assert_eq!(1_500.abbrev(), abbrev_num(1_500, None));
+        assert_eq!((-1_500_i16).abbrev(), Some("-1.5k".to_string()));
+        assert_eq!(200_u8.abbrev(), Some("200".to_string()));
+        assert_eq!(u64::MAX.abbrev(), Some("18.4E".to_string()));
+        assert_eq!(
+            1_234_567_usize.abbrev_with(options),
+            Some("1.23 M".to_string())
+        );
+        assert_eq!(i64::MIN.abbrev_with(options), Some("-9.22 E".to_string()));
+    }
+}
@@ -0,0 +1,386 @@
+use crate::{Align, Grouping, NegativeStyle, Normalization, Options, SignPosition};
+use alloc::string::String;
+use core::fmt::{self, Write};
+use rust_decimal::Decimal;
+
+/// The numeric part of an abbreviated number.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Mantissa {
+    /// Zero, which is written as `0` unless [Options::zero_text] is set.
+    Zero,
+    /// An integer that is written as is.
+    Integer(u128),
+    /// A rounded and normalized decimal.
+    Decimal(Decimal),
+}
+
+/// The parts of an abbreviated number, resolved before anything is written so that
+/// failures never produce partial output.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct Parts<'a> {
+    pub(crate) negative: bool,
+    pub(crate) mantissa: Mantissa,
+    pub(crate) unit: &'a str,
+    /// The level of the unit among the abbreviation units, or of its breakpoint in
+    /// [Options::unit_scale] starting at `1`. This is `0` for numbers without a scaled
+    /// unit, such as in scientific notation.
+    pub(crate) level: u32,
+    /// A fixed unit appended to the abbreviation unit, such as `B` for bytes.
+    pub(crate) unit_suffix: &'a str,
+    /// The power of ten written after the mantissa in scientific notation.
+    pub(crate) exponent: Option<i32>,
+    /// The integer that the number was divided by, which is one for numbers that are not
+    /// scaled down.
+    pub(crate) divisor: u128,
+    /// The precision that the mantissa was rounded to, in place of [Options::precision].
+    pub(crate) precision: Option<u32>,
+    /// Whether the number was capped by [Options::saturate_at].
+    pub(crate) saturated: bool,
+    /// Whether [Options::space] and [Options::unit_separator] apply between the unit and
+    /// the unit suffix instead of before the unit, as for rates such as `2.5k req/s`.
+    pub(crate) spaced_suffix: bool,
+    /// The meaningful decimal places of the mantissa when limited by
+    /// [Options::clamp_precision], which no padding goes beyond.
+    pub(crate) places: Option<u32>,
+}
+
+impl Parts<'_> {
+    /// Writes the abbreviated number into the given sink, padded to the width from the
+    /// options.
+    pub(crate) fn write<W: Write>(&self, out: &mut W, options: &Options) -> fmt::Result {
+        let Some(width) = options.width else {
+            return self.write_unpadded(out, options);
+        };
+
+        // The result is measured first, so that padding does not need a buffer.
+        let padding = width.saturating_sub(self.width(options));
+        let fill = options.fill.unwrap_or(' ');
+
+        match options.align {
+            Align::Right => {
+                write_fill(out, fill, padding)?;
+                self.write_unpadded(out, options)
+            }
+            Align::Left => {
+                self.write_unpadded(out, options)?;
+                write_fill(out, fill, padding)
+            }
+        }
+    }
+
+    /// Writes the abbreviated number into the given sink without any padding.
+    fn write_unpadded<W: Write>(&self, out: &mut W, options: &Options) -> fmt::Result {
+        if let (Mantissa::Zero, Some(text)) = (self.mantissa, options.zero_text) {
+            return out.write_str(text);
+        }
+
+        let parentheses =
+            self.is_negative() && options.negative_style == NegativeStyle::Parentheses;
+        let sign = if self.is_negative() {
+            if parentheses {
+                ""
+            } else {
+                "-"
+            }
+        } else if options.force_sign && (self.mantissa != Mantissa::Zero || options.force_sign_zero)
+        {
+            "+"
+        } else {
+            ""
+        };
+        let prefix = options.prefix.unwrap_or_default();
+
+        if parentheses {
+            out.write_char('(')?;
+        }
+
+        match options.sign_position {
+            SignPosition::BeforePrefix => {
+                out.write_str(sign)?;
+                out.write_str(prefix)?;
+            }
+            SignPosition::AfterPrefix => {
+                out.write_str(prefix)?;
+                out.write_str(sign)?;
+            }
+            SignPosition::Suffix => out.write_str(prefix)?,
+        }
+
+        if self.spaced_suffix {
+            let (unit_separator, suffix_separator) = self.suffix_separators(options);
+
+            if options.unit_before {
+                out.write_str(self.unit)?;
+                out.write_str(suffix_separator)?;
+                out.write_str(self.unit_suffix)?;
+                out.write_str(unit_separator)?;
+                self.write_mantissa(out, options)?;
+            } else {
+                self.write_mantissa(out, options)?;
+                out.write_str(unit_separator)?;
+                out.write_str(self.unit)?;
+                out.write_str(suffix_separator)?;
+                out.write_str(self.unit_suffix)?;
+            }
+        } else if options.unit_before {
+            out.write_str(self.unit)?;
+            out.write_str(self.unit_suffix)?;
+            out.write_str(self.separator(options))?;
+            self.write_mantissa(out, options)?;
+        } else {
+            self.write_mantissa(out, options)?;
+            out.write_str(self.separator(options))?;
+            out.write_str(self.unit)?;
+            out.write_str(self.unit_suffix)?;
+        }
+
+        if self.saturated {
+            out.write_str(options.saturate_suffix.unwrap_or("+"))?;
+        }
+
+        if options.sign_position == SignPosition::Suffix {
+            out.write_str(sign)?;
+        }
+
+        out.write_str(options.suffix.unwrap_or_default())?;
+
+        if parentheses {
+            out.write_char(')')?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of characters of the abbreviated number without any padding.
+    pub(crate) fn width(&self, options: &Options) -> usize {
+        let mut counter = Counter::default();
+        // Counting characters never fails.
+        let _ = self.write_unpadded(&mut counter, options);
+        counter.chars
+    }
+
+    /// Returns the number of characters written by [Parts::write], including the padding
+    /// to the width from the options.
+    pub(crate) fn padded_width(&self, options: &Options) -> usize {
+        let width = self.width(options);
+
+        options.width.map_or(width, |padded| padded.max(width))
+    }
+
+    /// Returns the number of characters of the mantissa alone.
+    pub(crate) fn mantissa_width(&self, options: &Options) -> usize {
+        let mut counter = Counter::default();
+        // Counting characters never fails.
+        let _ = self.write_mantissa(&mut counter, options);
+        counter.chars
+    }
+
+    /// Returns whether the number is written with a negative sign.
+    pub(crate) fn is_negative(&self) -> bool {
+        self.negative && self.mantissa != Mantissa::Zero
+    }
+
+    /// Writes the mantissa into the given sink, applying the separators from the options.
+    pub(crate) fn write_mantissa<W: Write>(&self, out: &mut W, options: &Options) -> fmt::Result {
+        let clamped = self.places.map(|places| Options {
+            min_precision: options.min_precision.map(|min| min.min(places)),
+            ..*options
+        });
+        let options = clamped.as_ref().unwrap_or(options);
+        let mut digits = Buffer::default();
+
+        match self.mantissa {
+            Mantissa::Zero => digits.write_char('0')?,
+            Mantissa::Integer(value) => write!(digits, "{value}")?,
+            Mantissa::Decimal(value) => write!(digits, "{value}")?,
+        }
+
+        if options.always_decimal && self.divisor > 1 {
+            let options = Options {
+                min_precision: Some(options.min_precision.unwrap_or(0).max(1)),
+                ..*options
+            };
+
+            write_mantissa(out, digits.as_str(), self.precision, &options)?;
+        } else {
+            write_mantissa(out, digits.as_str(), self.precision, options)?;
+        }
+
+        match self.exponent {
+            Some(0) if options.hide_zero_exponent => Ok(()),
+            Some(exponent) if options.superscript_exponent => {
+                out.write_str("×10")?;
+                write_superscript(out, exponent)
+            }
+            Some(exponent) => write!(out, "e{exponent}"),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the separator between the mantissa and the unit.
+    pub(crate) fn separator<'b>(&self, options: &Options<'b>) -> &'b str {
+        if self.unit.is_empty() && self.unit_suffix.is_empty() {
+            ""
+        } else if let Some(separator) = options.unit_separator {
+            separator
+        } else if options.space || options.long_words {
+            " "
+        } else {
+            ""
+        }
+    }
+
+    /// Returns the separators before the unit and before the unit suffix of parts with
+    /// [Parts::spaced_suffix] set. Only word units are spaced from the mantissa, while
+    /// the unit suffix always follows the separator from the options.
+    fn suffix_separators<'b>(&self, options: &Options<'b>) -> (&'b str, &'b str) {
+        let unit_separator = if options.long_words && !self.unit.is_empty() {
+            " "
+        } else {
+            ""
+        };
+        let suffix_separator = match options.unit_separator {
+            Some(separator) => separator,
+            None if options.space => " ",
+            None => "",
+        };
+
+        (unit_separator, suffix_separator)
+    }
+
+    /// Returns the abbreviated number as a string.
+    pub(crate) fn to_string(self, options: &Options) -> String {
+        let mut result = String::new();
+        // Writing into a string never fails.
+        let _ = self.write(&mut result, options);
+        result
+    }
+}
+
+/// Writes a plain formatted mantissa, applying the separators from the options and
+/// padding it to the given precision if set.
+fn write_mantissa<W: Write>(
+    out: &mut W,
+    mantissa: &str,
+    precision: Option<u32>,
+    options: &Options,
+) -> fmt::Result {
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let fixed = options.keep_trailing_zeros || options.normalization == Normalization::Fixed;
+    let min_precision = if fixed && options.max_precision.is_none() {
+        precision
+            .or(options.precision)
+            .unwrap_or(1)
+            .max(options.min_precision.unwrap_or(0))
+    } else {
+        options.min_precision.unwrap_or(0)
+    };
+    let padding = (min_precision as usize).saturating_sub(fraction.len());
+
+    for (index, digit) in integer.chars().enumerate() {
+        if let Some(separator) = options.group_separator {
+            let remaining = integer.len() - index;
+            let boundary = match options.grouping {
+                Grouping::Thousands => remaining % 3 == 0,
+                Grouping::Indian => remaining >= 3 && remaining % 2 == 1,
+            };
+
+            if index > 0 && boundary {
+                out.write_char(separator)?;
+            }
+        }
+
+        out.write_char(digit)?;
+    }
+
+    if !fraction.is_empty() || padding > 0 {
+        out.write_char(options.decimal_separator.unwrap_or('.'))?;
+
+        let digits = fraction.chars().chain(core::iter::repeat_n('0', padding));
+
+        for (index, digit) in digits.enumerate() {
+            if let (Some(separator), true) = (options.group_separator, options.group_fraction) {
+                if index > 0 && index % 3 == 0 {
+                    out.write_char(separator)?;
+                }
+            }
+
+            out.write_char(digit)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes an exponent using superscript digits and minus sign.
+fn write_superscript<W: Write>(out: &mut W, exponent: i32) -> fmt::Result {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    let mut digits = Buffer::default();
+    write!(digits, "{exponent}")?;
+
+    for char in digits.as_str().chars() {
+        match char.to_digit(10) {
+            Some(digit) => out.write_char(DIGITS[digit as usize])?,
+            None => out.write_char('⁻')?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the fill character the given number of times.
+pub(crate) fn write_fill<W: Write>(out: &mut W, fill: char, count: usize) -> fmt::Result {
+    for _ in 0..count {
+        out.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+/// A sink that only counts the characters written into it.
+#[derive(Default)]
+struct Counter {
+    chars: usize,
+}
+
+impl Write for Counter {
+    fn write_str(&mut self, value: &str) -> fmt::Result {
+        self.chars += value.chars().count();
+        Ok(())
+    }
+}
+
+/// A fixed-size buffer for formatting numbers without allocating. It fits any `u128` or
+/// [Decimal].
+pub(crate) struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Buffer {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+}
+
+impl Buffer {
+    pub(crate) fn as_str(&self) -> &str {
+        // Only complete strings are ever written into the buffer.
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, value: &str) -> fmt::Result {
+        let end = self.len + value.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(value.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
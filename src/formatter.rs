@@ -0,0 +1,243 @@
+use crate::{
+    base, fitted_parts, fixed_level, integer_parts, max_level, AbbrevError, Integer, Options,
+};
+use alloc::string::String;
+use rust_decimal::Decimal;
+
+/// A reusable formatter that abbreviates numbers with the same options, validated once
+/// when it is created.
+///
+/// Each result is exactly what [abbrev](crate::abbrev) returns for the number. If the
+/// options are invalid, such as with an empty abbreviation list, an unknown
+/// [Options::fixed_unit], units that [Options::validated] rejects or a precision above
+/// `28` decimal places, every number fails with the same [AbbrevError].
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{NumberFormatter, Options};
+///
+/// let formatter = NumberFormatter::new(Options {
+///     precision: Some(2),
+///     ..Default::default()
+/// });
+///
+/// assert_eq!(formatter.format(1_234), Some("1.23k".to_string()));
+/// assert_eq!(formatter.format(-5_670_000_i64), Some("-5.67M".to_string()));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NumberFormatter<'a> {
+    options: Options<'a>,
+    error: Option<AbbrevError>,
+}
+
+impl<'a> NumberFormatter<'a> {
+    /// Creates a formatter for the given options, validating them up front.
+    pub fn new(options: Options<'a>) -> Self {
+        NumberFormatter {
+            options,
+            error: validate(&options).err(),
+        }
+    }
+
+    /// Returns the options of the formatter.
+    pub fn options(&self) -> &Options<'a> {
+        &self.options
+    }
+
+    /// Returns the error of the options if they are invalid.
+    pub fn validate(&self) -> Result<(), AbbrevError> {
+        self.error.map_or(Ok(()), Err)
+    }
+
+    /// Abbreviates the given number, returning `None` if it cannot be abbreviated.
+    pub fn format(&self, number: impl Integer) -> Option<String> {
+        self.try_format(number).ok()
+    }
+
+    /// Abbreviates the given number, returning an [AbbrevError] if it cannot be
+    /// abbreviated.
+    pub fn try_format(&self, number: impl Integer) -> Result<String, AbbrevError> {
+        self.validate()?;
+
+        let (options, parts) =
+            fitted_parts(&self.options, |options| integer_parts(number, options, ""))?;
+
+        Ok(parts.to_string(&options))
+    }
+}
+
+impl<'a> From<Options<'a>> for NumberFormatter<'a> {
+    fn from(options: Options<'a>) -> Self {
+        NumberFormatter::new(options)
+    }
+}
+
+impl Default for NumberFormatter<'_> {
+    fn default() -> Self {
+        NumberFormatter::new(Options::default())
+    }
+}
+
+/// Checks the parts of the options that do not depend on the number.
+fn validate(options: &Options) -> Result<(), AbbrevError> {
+    if options.unit_scale.is_none() {
+        base(options)?;
+        max_level(options)?;
+        options.validated()?;
+    }
+
+    fixed_level(options)?;
+
+    let precisions = [
+        options.precision,
+        options.min_precision,
+        options.max_precision,
+    ];
+
+    if let Some(precision) = precisions
+        .into_iter()
+        .flatten()
+        .find(|precision| *precision > Decimal::MAX_SCALE)
+    {
+        return Err(AbbrevError::InvalidPrecision { precision });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abbrev;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn can_format_like_abbrev() {
+        let options = Options {
+            precision: Some(2),
+            space: true,
+            group_separator: Some(','),
+            ..Default::default()
+        };
+        let formatter = NumberFormatter::from(options);
+        let fixtures: Vec<i64> = vec![0, 150, -1_500, 1_234_567, i64::MIN, i64::MAX];
+
+        fixtures.iter().for_each(|case| {
+            assert_eq!(formatter.format(*case), abbrev(*case, Some(options)));
+        });
+
+        assert_eq!(formatter.options(), &options);
+        assert_eq!(formatter.validate(), Ok(()));
+        assert_eq!(
+            NumberFormatter::default().format(2_500_u32),
+            Some("2.5k".to_string())
+        );
+    }
+
+    #[test]
+    fn cannot_format_using_invalid_options() {
+        let fixtures: Vec<(Options, AbbrevError)> = vec![
+            (
+                Options {
+                    abbreviations: Some(&[]),
+                    ..Default::default()
+                },
+                AbbrevError::MagnitudeOutOfRange { level: 0 },
+            ),
+            (
+                Options {
+                    base: Some(1),
+                    ..Default::default()
+                },
+                AbbrevError::InvalidBase,
+            ),
+            (
+                Options {
+                    fixed_unit: Some("X"),
+                    ..Default::default()
+                },
+                AbbrevError::UnknownUnit,
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, expected)| {
+            let formatter = NumberFormatter::new(*options);
+            assert_eq!(formatter.validate(), Err(*expected));
+            assert_eq!(formatter.try_format(0), Err(*expected));
+            assert_eq!(formatter.format(1_500), None);
+        });
+
+        let formatter = NumberFormatter::new(Options {
+            abbreviations: Some(&[]),
+            unit_scale: Some(&[(1_000, "K")]),
+            ..Default::default()
+        });
+
+        assert_eq!(formatter.format(1_500), Some("1.5K".to_string()));
+    }
+
+    #[test]
+    fn rejects_duplicate_units() {
+        let formatter = NumberFormatter::new(Options {
+            abbreviations: Some(&["", "k", "k"]),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            formatter.validate(),
+            Err(AbbrevError::DuplicateUnit { level: 2 })
+        );
+        assert_eq!(
+            formatter.try_format(1_500),
+            Err(AbbrevError::DuplicateUnit { level: 2 })
+        );
+    }
+
+    #[test]
+    fn rejects_excessive_precision() {
+        let fixtures: Vec<(Options, Result<(), AbbrevError>)> = vec![
+            (
+                Options {
+                    precision: Some(28),
+                    ..Default::default()
+                },
+                Ok(()),
+            ),
+            (
+                Options {
+                    precision: Some(29),
+                    ..Default::default()
+                },
+                Err(AbbrevError::InvalidPrecision { precision: 29 }),
+            ),
+            (
+                Options {
+                    min_precision: Some(40),
+                    ..Default::default()
+                },
+                Err(AbbrevError::InvalidPrecision { precision: 40 }),
+            ),
+            (
+                Options {
+                    max_precision: Some(u32::MAX),
+                    ..Default::default()
+                },
+                Err(AbbrevError::InvalidPrecision {
+                    precision: u32::MAX,
+                }),
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, expected)| {
+            let formatter = NumberFormatter::new(*options);
+
+            assert_eq!(formatter.validate(), *expected);
+            assert_eq!(
+                formatter.try_format(1_500).map(|_| ()),
+                *expected,
+                "{options:?}"
+            );
+        });
+    }
+}
@@ -0,0 +1,43 @@
+mod private {
+    pub trait Sealed {}
+}
+
+/// A primitive integer type that can be abbreviated without any lossy conversion.
+///
+/// This trait is sealed and implemented for all of the standard integer primitives.
+pub trait Integer: Copy + private::Sealed {
+    /// Returns whether the number is negative, along with its absolute value.
+    #[doc(hidden)]
+    fn to_magnitude(self) -> (bool, u128);
+}
+
+macro_rules! impl_signed {
+    ($($type:ty),*) => {
+        $(
+            impl private::Sealed for $type {}
+
+            impl Integer for $type {
+                fn to_magnitude(self) -> (bool, u128) {
+                    (self.is_negative(), self.unsigned_abs() as u128)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_unsigned {
+    ($($type:ty),*) => {
+        $(
+            impl private::Sealed for $type {}
+
+            impl Integer for $type {
+                fn to_magnitude(self) -> (bool, u128) {
+                    (false, self as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
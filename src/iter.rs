@@ -0,0 +1,276 @@
+use crate::{abbrev_num, fitted_parts, integer_parts, Integer, Options};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// The placeholder written by [abbrev_column] for numbers that cannot be abbreviated.
+const PLACEHOLDER: &str = "?";
+
+/// Abbreviates each of the given numbers with the same options, right-aligning them into
+/// a block of lines padded to the widest result.
+///
+/// Numbers that cannot be abbreviated are written as `?`.
+///
+/// # Arguments
+///
+/// * `numbers` - The integers to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation,
+///   shared by all of the numbers.
+///
+/// # Returns
+///
+/// The abbreviated numbers joined by newlines, without a trailing newline.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev_column;
+///
+/// assert_eq!(abbrev_column(&[999, 1_500, -2_300_000], None), "  999\n 1.5k\n-2.3M");
+/// ```
+pub fn abbrev_column(numbers: &[isize], options: Option<Options>) -> String {
+    let results: Vec<String> = numbers
+        .iter()
+        .copied()
+        .abbrev_each(options)
+        .map(|result| result.unwrap_or_else(|| PLACEHOLDER.to_string()))
+        .collect();
+    let width = results
+        .iter()
+        .map(|result| result.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut column = String::new();
+
+    for (index, result) in results.iter().enumerate() {
+        if index > 0 {
+            column.push('\n');
+        }
+
+        // Writing into a string never fails.
+        let _ = write!(column, "{result:>width$}");
+    }
+
+    column
+}
+
+/// Lazily pairs each of the given numbers with its abbreviated form, such as for logging
+/// or building lookup tables.
+///
+/// # Arguments
+///
+/// * `numbers` - The integers to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation,
+///   shared by all of the numbers.
+///
+/// # Returns
+///
+/// An iterator over each number along with what [abbrev_num] returns for it.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev_pairs;
+///
+/// let pairs: Vec<_> = abbrev_pairs([999, -1_500], None).collect();
+///
+/// assert_eq!(
+///     pairs,
+///     vec![(999, Some("999".to_string())), (-1_500, Some("-1.5k".to_string()))]
+/// );
+/// ```
+pub fn abbrev_pairs<'a, I>(
+    numbers: I,
+    options: Option<Options<'a>>,
+) -> impl Iterator<Item = (isize, Option<String>)> + 'a
+where
+    I: IntoIterator<Item = isize>,
+    I::IntoIter: 'a,
+{
+    let options = options.unwrap_or_default();
+
+    numbers
+        .into_iter()
+        .map(move |number| (number, abbrev_num(number, Some(options))))
+}
+
+/// An iterator that abbreviates each number of the underlying iterator with the same
+/// options, created with [AbbrevIterExt::abbrev_each].
+///
+/// Each item is exactly what [abbrev_num](crate::abbrev_num) returns for the number.
+#[derive(Debug, Clone)]
+pub struct AbbrevIter<'a, I> {
+    iter: I,
+    options: Options<'a>,
+}
+
+impl<I> Iterator for AbbrevIter<'_, I>
+where
+    I: Iterator,
+    I::Item: Integer,
+{
+    type Item = Option<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|number| self.abbreviate(number))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for AbbrevIter<'_, I>
+where
+    I: DoubleEndedIterator,
+    I::Item: Integer,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|number| self.abbreviate(number))
+    }
+}
+
+impl<I> ExactSizeIterator for AbbrevIter<'_, I>
+where
+    I: ExactSizeIterator,
+    I::Item: Integer,
+{
+}
+
+impl<I> AbbrevIter<'_, I> {
+    /// Abbreviates a single number using the shared options.
+    fn abbreviate(&self, number: impl Integer) -> Option<String> {
+        let (options, parts) =
+            fitted_parts(&self.options, |options| integer_parts(number, options, "")).ok()?;
+        Some(parts.to_string(&options))
+    }
+}
+
+/// An extension trait to lazily abbreviate every number of an iterator, implemented for
+/// all iterators over the standard integer primitives.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::AbbrevIterExt;
+///
+/// let ticks: Vec<isize> = vec![0, 500, 1_000, 1_500];
+/// let labels: Vec<_> = ticks.iter().copied().abbrev_each(None).collect();
+///
+/// assert_eq!(
+///     labels,
+///     vec![
+///         Some("0".to_string()),
+///         Some("500".to_string()),
+///         Some("1k".to_string()),
+///         Some("1.5k".to_string()),
+///     ]
+/// );
+/// ```
+pub trait AbbrevIterExt: Iterator + Sized
+where
+    Self::Item: Integer,
+{
+    /// Returns an [AbbrevIter] that abbreviates each number with the given options.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - An optional parameter specifying the [Options] for abbreviation,
+    ///   shared by all of the numbers.
+    fn abbrev_each<'a>(self, options: Option<Options<'a>>) -> AbbrevIter<'a, Self> {
+        AbbrevIter {
+            iter: self,
+            options: options.unwrap_or_default(),
+        }
+    }
+}
+
+impl<I> AbbrevIterExt for I
+where
+    I: Iterator,
+    I::Item: Integer,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_abbreviate_each_number() {
+        let options = Options {
+            precision: Some(2),
+            abbreviations: Some(&["", "k"]),
+            ..Default::default()
+        };
+        let numbers: Vec<isize> = vec![0, -150, 1_234, 999_999, 1_500_000];
+        let expected: Vec<_> = numbers
+            .iter()
+            .map(|number| abbrev_num(*number, Some(options)))
+            .collect();
+
+        let iter = numbers.iter().copied().abbrev_each(Some(options));
+
+        assert_eq!(iter.len(), numbers.len());
+        assert_eq!(iter.collect::<Vec<_>>(), expected);
+        assert_eq!(
+            numbers
+                .iter()
+                .copied()
+                .abbrev_each(Some(options))
+                .next_back(),
+            Some(None)
+        );
+        assert_eq!(
+            (0_u64..3).abbrev_each(None).collect::<Vec<_>>(),
+            vec![
+                Some("0".to_string()),
+                Some("1".to_string()),
+                Some("2".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_into_column() {
+        let options = Options {
+            abbreviations: Some(&["", "k"]),
+            prefix: Some("€"),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_column(&[0, -999, 1_500, 1_500_000], Some(options)),
+            "   €0\n-€999\n€1.5k\n    ?"
+        );
+        assert_eq!(abbrev_column(&[], None), "");
+        assert_eq!(abbrev_column(&[5], None), "5");
+    }
+
+    #[test]
+    fn can_pair_numbers_with_abbreviations() {
+        let options = Options {
+            abbreviations: Some(&["", "k"]),
+            ..Default::default()
+        };
+        let pairs: Vec<_> =
+            abbrev_pairs(vec![0, 1_234, -999_000, 1_500_000], Some(options)).collect();
+
+        assert_eq!(
+            pairs,
+            vec![
+                (0, Some("0".to_string())),
+                (1_234, Some("1.2k".to_string())),
+                (-999_000, Some("-999k".to_string())),
+                (1_500_000, None),
+            ]
+        );
+
+        let mut evaluated = 0;
+        let mut pairs = abbrev_pairs((0..).inspect(|_| evaluated += 1), None);
+
+        assert_eq!(pairs.nth(2), Some((2, Some("2".to_string()))));
+        drop(pairs);
+        assert_eq!(evaluated, 3);
+    }
+}
@@ -1,26 +1,120 @@
-use lazy_static::lazy_static;
-use rust_decimal::prelude::FromPrimitive;
-use rust_decimal::Decimal;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-pub use rust_decimal::RoundingStrategy;
+extern crate alloc;
 
-lazy_static! {
-    /// The default list of abbreviation units.
-    pub static ref ABBREVIATIONS: [&'static str; 7] = ["", "k", "M", "B", "T", "P", "E"];
-}
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::fmt;
+use format::{Buffer, Mantissa, Parts};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+
+#[cfg(feature = "bigint")]
+pub use bigint::abbrev_bigint;
+pub use builder::OptionsBuilder;
+pub use display::Abbreviated;
+pub use error::{AbbrevError, SpecError};
+pub use ext::AbbrevExt;
+pub use formatter::NumberFormatter;
+pub use integer::Integer;
+pub use iter::{abbrev_column, abbrev_pairs, AbbrevIter, AbbrevIterExt};
+pub use options::{
+    Align, Grouping, Locale, NegativeStyle, Normalization, NotationMode, NumberScale, Options,
+    Rounding, SignPosition,
+};
+pub use owned::{abbrev_owned, OwnedOptions};
+pub use parse::parse_abbrev;
+pub use parts::{abbrev_delta, abbrev_mantissa, abbrev_parts, AbbrevParts, Sign, Trend};
+pub use rust_decimal::{Decimal, RoundingStrategy};
+pub use table::{format_with_table, ScaleTable};
+#[cfg(feature = "test-util")]
+pub use test_util::assert_roundtrip;
+
+#[cfg(feature = "bigint")]
+mod bigint;
+mod builder;
+mod display;
+mod error;
+mod ext;
+mod format;
+mod formatter;
+mod integer;
+mod iter;
+#[cfg(feature = "macros")]
+mod macros;
+mod options;
+mod owned;
+mod parse;
+mod parts;
+#[cfg(feature = "serde")]
+mod serialization;
+mod spec;
+mod table;
+#[cfg(feature = "test-util")]
+mod test_util;
+
+/// The default list of abbreviation units.
+pub const DEFAULT_ABBREVIATIONS: [&str; 9] = ["", "k", "M", "B", "T", "P", "E", "Z", "Y"];
 
-/// The options for abbreviating a number.
-#[derive(Debug, Default, Copy, Clone)]
-pub struct Options<'a> {
-    /// The precision of the result. `1` by default.
-    pub precision: Option<u32>,
-    /// A list of custom abbreviation units. [ABBREVIATIONS] is used by default.
-    pub abbreviations: Option<[&'a str; 7]>,
-    /// The [RoundingStrategy] to use on the result.
-    /// [RoundingStrategy::MidpointNearestEven] is used by default.
-    pub rounding_strategy: Option<RoundingStrategy>,
+/// The default list of abbreviation units, dereferencing to [DEFAULT_ABBREVIATIONS] as the
+/// lazily initialized table once did.
+#[deprecated(note = "use `DEFAULT_ABBREVIATIONS` instead")]
+pub static ABBREVIATIONS: DefaultAbbreviations = DefaultAbbreviations;
+
+/// The type of the deprecated [ABBREVIATIONS] table, which dereferences to
+/// [DEFAULT_ABBREVIATIONS].
+#[derive(Debug, Clone, Copy)]
+pub struct DefaultAbbreviations;
+
+impl core::ops::Deref for DefaultAbbreviations {
+    type Target = [&'static str; 9];
+
+    fn deref(&self) -> &Self::Target {
+        &DEFAULT_ABBREVIATIONS
+    }
 }
 
+/// The list of full-word units, used when [Options::long_words] is set.
+pub static LONG_ABBREVIATIONS: [&str; 9] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+];
+
+/// The list of long scale full-word units, used when [Options::long_words] is set
+/// along with [NumberScale::Long].
+pub static LONG_SCALE_ABBREVIATIONS: [&str; 9] = [
+    "",
+    "thousand",
+    "million",
+    "milliard",
+    "billion",
+    "billiard",
+    "trillion",
+    "trilliard",
+    "quadrillion",
+];
+
+/// The list of IEC binary prefixes, used with a base of `1024`.
+pub static BINARY_ABBREVIATIONS: [&str; 9] = ["", "Ki", "Mi", "Gi", "Ti", "Pi", "Ei", "Zi", "Yi"];
+
+/// The list of SI byte units, used with the default base of `1000`.
+pub static BYTE_ABBREVIATIONS: [&str; 9] = ["B", "kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB"];
+
+/// The list of SI prefixes for bit rates, used with the default base of `1000`.
+pub static BIT_ABBREVIATIONS: [&str; 7] = ["", "k", "M", "G", "T", "P", "E"];
+
+/// The list of East Asian myriad units, used with a step of `4`.
+pub static MYRIAD_ABBREVIATIONS: [&str; 6] = ["", "万", "億", "兆", "京", "垓"];
+
+/// The list of SI prefixes for values below one, in descending order.
+pub static SMALL_ABBREVIATIONS: [&str; 4] = ["m", "µ", "n", "p"];
+
 /// Abbreviates the given number into a human-friendly format according to specified
 /// options.
 ///
@@ -48,111 +142,5390 @@ pub struct Options<'a> {
 /// assert_eq!(abbrev_num(10_500, Some(options)), Some("10.5k".to_string()));
 /// ```
 pub fn abbrev_num(number: isize, options: Option<Options>) -> Option<String> {
-    if number == 0 {
-        return Some("0".to_string());
-    }
+    abbrev_cow(number, options).map(Cow::into_owned)
+}
+
+/// Abbreviates the given number into a human-friendly format according to specified
+/// options, borrowing the result instead of allocating it where possible.
+///
+/// This behaves exactly like [abbrev_num], but results consisting of a single digit,
+/// such as zero, are borrowed from static strings. This avoids allocations when most
+/// numbers are small.
+///
+/// # Arguments
+///
+/// * `number` - The integer to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated form of the number.
+/// Returns `None` if the number is out of bounds or cannot be abbreviated using the
+/// provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev_cow;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(abbrev_cow(0, None), Some(Cow::Borrowed("0")));
+/// assert_eq!(abbrev_cow(1_500, None), Some(Cow::Owned("1.5k".to_string())));
+/// ```
+pub fn abbrev_cow(number: isize, options: Option<Options>) -> Option<Cow<'static, str>> {
+    const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
 
     let options = options.unwrap_or_default();
-    let absolute = number.unsigned_abs();
-    let level = absolute.ilog10() / 3;
-    let sign = if number.is_negative() { "-" } else { "" };
-    let precision = options.precision.unwrap_or(1);
+    let (options, parts) =
+        fitted_parts(&options, |options| integer_parts(number, options, "")).ok()?;
 
-    let abbreviation = if let Some(abbreviations) = options.abbreviations {
-        abbreviations.get(level as usize).copied()
-    } else {
-        ABBREVIATIONS.get(level as usize).copied()
-    }?;
+    // Most results fit in a buffer on the stack, so they are only allocated once known.
+    let mut buffer = Buffer::default();
 
-    if level == 0 {
-        return Some(format!("{sign}{absolute}{abbreviation}"));
+    if parts.write(&mut buffer, &options).is_err() {
+        return Some(Cow::Owned(parts.to_string(&options)));
     }
 
-    let result = absolute as f64 / 10_f64.powi(level as i32 * 3);
-    let result = Decimal::from_f64(result)?.round_dp_with_strategy(
-        precision,
-        options
-            .rounding_strategy
-            .unwrap_or(RoundingStrategy::MidpointNearestEven),
-    );
+    match DIGITS.iter().find(|digit| **digit == buffer.as_str()) {
+        Some(digit) => Some(Cow::Borrowed(digit)),
+        None => Some(Cow::Owned(buffer.as_str().to_string())),
+    }
+}
+
+/// Abbreviates the given number according to specified options, always rounding the
+/// result up towards positive infinity.
+///
+/// This overrides [Options::rounding_strategy] and [Options::negative_rounding_strategy],
+/// so that a result never understates the number. Negative numbers are rounded towards
+/// zero, such as `-1.9M` to `-1M` at a precision of zero.
+///
+/// # Arguments
+///
+/// * `number` - The integer to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated form of the number.
+/// Returns `None` if the number is out of bounds or cannot be abbreviated using the
+/// provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{abbrev_num_ceil, Options};
+///
+/// let options = Options {
+///     precision: Some(0),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(abbrev_num_ceil(1_100_000, Some(options)), Some("2M".to_string()));
+/// ```
+pub fn abbrev_num_ceil(number: isize, options: Option<Options>) -> Option<String> {
+    let options = options.unwrap_or_default().with_rounding(Rounding::Up);
 
-    Some(format!("{sign}{}{abbreviation}", result.normalize()))
+    abbrev_num(number, Some(options))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Abbreviates the given number according to specified options, always rounding the
+/// result down towards negative infinity.
+///
+/// This overrides [Options::rounding_strategy] and [Options::negative_rounding_strategy],
+/// so that a result never overstates the number. Negative numbers are rounded away from
+/// zero, such as `-1.1M` to `-2M` at a precision of zero.
+///
+/// # Arguments
+///
+/// * `number` - The integer to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated form of the number.
+/// Returns `None` if the number is out of bounds or cannot be abbreviated using the
+/// provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{abbrev_num_floor, Options};
+///
+/// let options = Options {
+///     precision: Some(0),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(abbrev_num_floor(1_900_000, Some(options)), Some("1M".to_string()));
+/// ```
+pub fn abbrev_num_floor(number: isize, options: Option<Options>) -> Option<String> {
+    let options = options.unwrap_or_default().with_rounding(Rounding::Down);
 
-    #[test]
-    fn can_abbreviate_numbers() {
-        let fixtures: Vec<(isize, &str)> = vec![
-            (0, "0"),
-            (-0, "0"),
-            (1, "1"),
-            (10, "10"),
-            (150, "150"),
-            (999, "999"),
-            (1_000, "1k"),
-            (1_200, "1.2k"),
-            (10_000, "10k"),
-            (150_000, "150k"),
-            (1_000_000, "1M"),
-            (4_500_000, "4.5M"),
-            (-10, "-10"),
-            (-1_500, "-1.5k"),
-        ];
+    abbrev_num(number, Some(options))
+}
 
-        fixtures.iter().for_each(|(case, expected)| {
-            let result = abbrev_num(*case, None);
-            assert_eq!(result, Some(expected.to_string()));
-        });
-    }
+/// Abbreviates the given number into a human-friendly format according to specified
+/// options, describing why it cannot be abbreviated on failure.
+///
+/// # Arguments
+///
+/// * `number` - The integer to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Ok(value)`, a string representation of the abbreviated form of the number, or an
+/// [AbbrevError] if it cannot be abbreviated.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{try_abbrev_num, AbbrevError, Options};
+///
+/// let options = Options {
+///     abbreviations: Some(&["", "k"]),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(try_abbrev_num(1_500, Some(options)), Ok("1.5k".to_string()));
+/// assert_eq!(
+///     try_abbrev_num(1_500_000, Some(options)),
+///     Err(AbbrevError::MagnitudeOutOfRange { level: 2 })
+/// );
+/// ```
+pub fn try_abbrev_num(number: isize, options: Option<Options>) -> Result<String, AbbrevError> {
+    let mut result = String::new();
+    write_abbrev(&mut result, number, options)?;
+    Ok(result)
+}
 
-    #[test]
-    fn can_abbreviate_upto_a_certain_precision() {
-        let result = abbrev_num(
-            1_566_450,
-            Some(Options {
-                precision: Some(3),
-                ..Default::default()
-            }),
-        );
-        assert_eq!(result, Some("1.566M".to_string()));
+/// Writes the abbreviated form of the given number into a sink according to specified
+/// options, without allocating.
+///
+/// Nothing is written if the number cannot be abbreviated, so a reused buffer is left
+/// untouched on failure.
+///
+/// # Arguments
+///
+/// * `out` - The sink to write the abbreviation into, such as a reused [String].
+/// * `number` - The integer to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Ok(())` once the abbreviation is written, or an [AbbrevError] if the number cannot
+/// be abbreviated or the sink fails.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::write_abbrev;
+///
+/// let mut out = String::new();
+///
+/// write_abbrev(&mut out, 1_500, None).unwrap();
+/// out.push_str(", ");
+/// write_abbrev(&mut out, -2_300_000, None).unwrap();
+///
+/// assert_eq!(out, "1.5k, -2.3M");
+/// ```
+pub fn write_abbrev<W: fmt::Write>(
+    out: &mut W,
+    number: isize,
+    options: Option<Options>,
+) -> Result<(), AbbrevError> {
+    let options = options.unwrap_or_default();
+    let (options, parts) = fitted_parts(&options, |options| integer_parts(number, options, ""))?;
 
-        // Zero precision
-        let result = abbrev_num(
-            1_566_450,
-            Some(Options {
-                precision: Some(0),
-                ..Default::default()
-            }),
-        );
-        assert_eq!(result, Some("2M".to_string()));
-    }
+    Ok(parts.write(out, &options)?)
+}
 
-    #[test]
-    fn can_abbreviate_using_custom_units() {
-        let units: [&str; 7] = ["_c0", "_c1", "_c2", "_c3", "_c4", "_c5", "_c6"];
-        let fixtures: Vec<(isize, &str)> = vec![
-            (0, "0"),
-            (10, "10_c0"),
-            (1_000, "1_c1"),
-            (1_000_000, "1_c2"),
-            (1_000_000_000, "1_c3"),
-            (1_000_000_000_000, "1_c4"),
-            (1_000_000_000_000_000, "1_c5"),
-            (1_000_000_000_000_000_000, "1_c6"),
-        ];
+/// Abbreviates the given 128-bit integer into a human-friendly format according to
+/// specified options.
+///
+/// This behaves exactly like [abbrev_num], but accepts magnitudes beyond the range of
+/// `isize` without any lossy conversion.
+///
+/// # Arguments
+///
+/// * `number` - The integer to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated form of the number.
+/// Returns `None` if the number is out of bounds or cannot be abbreviated using the
+/// provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev_i128;
+///
+/// assert_eq!(
+///     abbrev_i128(250_000_000_000_000_000_000, None),
+///     Some("250E".to_string())
+/// );
+/// ```
+pub fn abbrev_i128(number: i128, options: Option<Options>) -> Option<String> {
+    let options = options.unwrap_or_default();
+    let (options, parts) =
+        fitted_parts(&options, |options| integer_parts(number, options, "")).ok()?;
 
-        fixtures.iter().for_each(|(case, expected)| {
-            let result = abbrev_num(
-                *case,
-                Some(Options {
-                    abbreviations: Some(units),
-                    ..Default::default()
-                }),
-            );
-            assert_eq!(result, Some(expected.to_string()));
-        });
+    Some(parts.to_string(&options))
+}
+
+/// Abbreviates the given integer of any primitive type into a human-friendly format
+/// according to specified options.
+///
+/// This behaves exactly like [abbrev_num], but accepts any [Integer] without casting,
+/// including magnitudes beyond the range of `isize`.
+///
+/// # Arguments
+///
+/// * `number` - The integer to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated form of the number.
+/// Returns `None` if the number is out of bounds or cannot be abbreviated using the
+/// provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev;
+///
+/// assert_eq!(abbrev(1_500_u16, None), Some("1.5k".to_string()));
+/// assert_eq!(abbrev(-2_000_i64, None), Some("-2k".to_string()));
+/// ```
+pub fn abbrev(number: impl Integer, options: Option<Options>) -> Option<String> {
+    let options = options.unwrap_or_default();
+    let (options, parts) =
+        fitted_parts(&options, |options| integer_parts(number, options, "")).ok()?;
+
+    Some(parts.to_string(&options))
+}
+
+/// Abbreviates the given number of bytes using IEC binary prefixes.
+///
+/// This applies a base of `1024` with [BINARY_ABBREVIATIONS] by default and appends a
+/// `B` unit to the result. Custom abbreviation units can still be provided through the
+/// options.
+///
+/// # Arguments
+///
+/// * `number` - The number of bytes to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated byte size. Returns `None`
+/// if the number cannot be abbreviated using the provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev_bytes;
+///
+/// assert_eq!(abbrev_bytes(1_536, None), Some("1.5KiB".to_string()));
+/// assert_eq!(abbrev_bytes(1_048_576, None), Some("1MiB".to_string()));
+/// ```
+pub fn abbrev_bytes(number: u64, options: Option<Options>) -> Option<String> {
+    let options = options.unwrap_or_default();
+    let options = Options {
+        base: Some(1024),
+        abbreviations: Some(options.abbreviations.unwrap_or(&BINARY_ABBREVIATIONS[..])),
+        ..options
+    };
+
+    let (options, parts) =
+        fitted_parts(&options, |options| integer_parts(number, options, "B")).ok()?;
+
+    Some(parts.to_string(&options))
+}
+
+/// Abbreviates the given number of bytes using SI units, where `1kB` equals `1000`
+/// bytes.
+///
+/// This applies [BYTE_ABBREVIATIONS] by default. Unlike [abbrev_num], zero is formatted
+/// with the first unit as well.
+///
+/// # Arguments
+///
+/// * `number` - The number of bytes to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated byte size. Returns `None`
+/// if the number cannot be abbreviated using the provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev_bytes_si;
+///
+/// assert_eq!(abbrev_bytes_si(512, None), Some("512B".to_string()));
+/// assert_eq!(abbrev_bytes_si(1_500_000, None), Some("1.5MB".to_string()));
+/// ```
+pub fn abbrev_bytes_si(number: u64, options: Option<Options>) -> Option<String> {
+    let options = options.unwrap_or_default();
+    let options = Options {
+        abbreviations: Some(options.abbreviations.unwrap_or(&BYTE_ABBREVIATIONS[..])),
+        ..options
+    };
+
+    let (options, parts) =
+        fitted_parts(&options, |options| integer_parts(number, options, "")).ok()?;
+
+    Some(parts.to_string(&options))
+}
+
+/// Abbreviates the given number of bits per second using SI prefixes, such as `1.5Mbps`.
+///
+/// This applies [BIT_ABBREVIATIONS] by default and appends a `bps` unit to the result,
+/// or `bit/s` when [Options::long_bit_rate] is set. Like [abbrev_bytes_si], zero is
+/// formatted with the unit as well.
+///
+/// # Arguments
+///
+/// * `number` - The number of bits per second to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated bit rate. Returns `None` if
+/// the number cannot be abbreviated using the provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{abbrev_bits, Options};
+///
+/// let options = Options {
+///     long_bit_rate: true,
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(abbrev_bits(1_500_000, None), Some("1.5Mbps".to_string()));
+/// assert_eq!(
+///     abbrev_bits(1_500_000, Some(options)),
+///     Some("1.5Mbit/s".to_string())
+/// );
+/// ```
+pub fn abbrev_bits(number: u64, options: Option<Options>) -> Option<String> {
+    let options = options.unwrap_or_default();
+    let options = Options {
+        abbreviations: Some(options.abbreviations.unwrap_or(&BIT_ABBREVIATIONS[..])),
+        ..options
+    };
+    let unit_suffix = if options.long_bit_rate {
+        "bit/s"
+    } else {
+        "bps"
+    };
+
+    let (options, parts) = fitted_parts(&options, |options| {
+        integer_parts(number, options, unit_suffix)
+    })
+    .ok()?;
+
+    Some(parts.to_string(&options))
+}
+
+/// Abbreviates the given rate into a human-friendly format according to specified
+/// options, appending a per-unit suffix such as `B/s` or `req/s`.
+///
+/// The suffix is written right after the abbreviation unit, so [Options::space] and
+/// [Options::unit_separator] apply between the abbreviated number and the suffix
+/// instead, such as `2.5k req/s`. [Options::suffix] is still written after it.
+///
+/// # Arguments
+///
+/// * `number` - The rate to be abbreviated.
+/// * `unit_suffix` - The per-unit suffix appended to the abbreviation unit.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated rate. Returns `None` if the
+/// number is out of bounds or cannot be abbreviated using the provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{abbrev_rate, Options};
+///
+/// let options = Options {
+///     space: true,
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(abbrev_rate(1_500_000, "B/s", None), Some("1.5MB/s".to_string()));
+/// assert_eq!(
+///     abbrev_rate(2_500, "req/s", Some(options)),
+///     Some("2.5k req/s".to_string())
+/// );
+/// ```
+pub fn abbrev_rate(
+    number: impl Integer,
+    unit_suffix: &str,
+    options: Option<Options>,
+) -> Option<String> {
+    let options = options.unwrap_or_default();
+    let (options, parts) = fitted_parts(&options, |options| {
+        let parts = integer_parts(number, options, unit_suffix)?;
+
+        Ok(Parts {
+            spaced_suffix: true,
+            ..parts
+        })
+    })
+    .ok()?;
+
+    Some(parts.to_string(&options))
+}
+
+/// Abbreviates the given floating-point number into a human-friendly format according
+/// to specified options.
+///
+/// Unlike [abbrev_num], the fractional part of numbers below the first unit is kept
+/// and rounded according to the precision. Values below one are abbreviated only when
+/// [Options::small_units] is set.
+///
+/// # Arguments
+///
+/// * `number` - The floating-point number to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated form of the number.
+/// Returns `None` if the number is `NaN`, infinite, out of bounds, or cannot be
+/// abbreviated using the provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev_float;
+///
+/// assert_eq!(abbrev_float(1_500.0, None), Some("1.5k".to_string()));
+/// assert_eq!(abbrev_float(0.5, None), Some("0.5".to_string()));
+/// ```
+pub fn abbrev_float(number: f64, options: Option<Options>) -> Option<String> {
+    let options = options.unwrap_or_default();
+    let (options, parts) = fitted_parts(&options, |options| float_parts(number, options)).ok()?;
+
+    Some(parts.to_string(&options))
+}
+
+/// Abbreviates the given single-precision floating-point number into a human-friendly
+/// format according to specified options.
+///
+/// This behaves like [abbrev_float], but the number is converted from its shortest `f32`
+/// representation rather than widened to `f64`, so `2.45_f32` is rounded as `2.45`
+/// instead of `2.4500000476837158`.
+///
+/// # Arguments
+///
+/// * `number` - The floating-point number to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated form of the number.
+/// Returns `None` if the number is `NaN`, infinite, out of bounds, or cannot be
+/// abbreviated using the provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev_f32;
+///
+/// assert_eq!(abbrev_f32(1_500.0, None), Some("1.5k".to_string()));
+/// assert_eq!(abbrev_f32(f32::NAN, None), None);
+/// ```
+pub fn abbrev_f32(number: f32, options: Option<Options>) -> Option<String> {
+    let options = options.unwrap_or_default();
+    let (options, parts) = fitted_parts(&options, |options| f32_parts(number, options)).ok()?;
+
+    Some(parts.to_string(&options))
+}
+
+/// Abbreviates the given ratio as a percentage according to specified options.
+///
+/// The ratio is multiplied by `100` without any floating-point error before it is
+/// rounded, and a `%` unit is appended to the result. Large percentages are abbreviated
+/// as well, which can be avoided with [Options::min_abbrev_value].
+///
+/// # Arguments
+///
+/// * `ratio` - The ratio to be formatted, where `1.0` equals `100%`.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the percentage. Returns `None` if the
+/// ratio is `NaN`, infinite, out of bounds, or cannot be abbreviated using the provided
+/// abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev_percent;
+///
+/// assert_eq!(abbrev_percent(0.1234, None), Some("12.3%".to_string()));
+/// assert_eq!(abbrev_percent(15.0, None), Some("1.5k%".to_string()));
+/// ```
+pub fn abbrev_percent(ratio: f64, options: Option<Options>) -> Option<String> {
+    if !ratio.is_finite() {
+        return None;
+    }
+
+    let options = options.unwrap_or_default();
+    let percentage = Decimal::from_f64(ratio)?.checked_mul(Decimal::ONE_HUNDRED)?;
+    let (options, parts) =
+        fitted_parts(&options, |options| decimal_parts(percentage, options, "%")).ok()?;
+
+    Some(parts.to_string(&options))
+}
+
+/// Abbreviates the given decimal into a human-friendly format according to specified
+/// options.
+///
+/// The number is leveled and rounded entirely as a [Decimal], so this is the most
+/// precise way to abbreviate fractional values such as amounts of money.
+///
+/// # Arguments
+///
+/// * `number` - The decimal to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated form of the number.
+/// Returns `None` if the number is out of bounds or cannot be abbreviated using the
+/// provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{abbrev_decimal, Decimal, Options};
+///
+/// let number: Decimal = "1234567.89".parse().unwrap();
+/// let options = Options {
+///     precision: Some(2),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(abbrev_decimal(number, Some(options)), Some("1.23M".to_string()));
+/// ```
+pub fn abbrev_decimal(number: Decimal, options: Option<Options>) -> Option<String> {
+    let options = options.unwrap_or_default();
+    let (options, parts) =
+        fitted_parts(&options, |options| decimal_parts(number, options, "")).ok()?;
+
+    Some(parts.to_string(&options))
+}
+
+/// Returns the level of abbreviation for the given number along with its unit, without
+/// formatting it.
+///
+/// The level is the power of the base that the number is divided by, or the position of
+/// its breakpoint in [Options::unit_scale] starting at `1`. Both are resolved exactly
+/// like [abbrev_num] resolves them, including numbers that are promoted to the next unit
+/// by rounding, so the unit is always the one that [abbrev_num] writes. Numbers that are
+/// not scaled down, such as in standard notation, are at level `0`. This is useful to
+/// lay out or group numbers by magnitude.
+///
+/// # Arguments
+///
+/// * `number` - The integer to be leveled.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some((level, unit))`, the level of the number and its abbreviation unit. Returns
+/// `None` if the number is out of bounds of the provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::unit_level;
+///
+/// assert_eq!(unit_level(999, None), Some((0, "")));
+/// assert_eq!(unit_level(-1_500_000, None), Some((2, "M")));
+/// assert_eq!(unit_level(999_999, None), Some((2, "M")));
+/// ```
+pub fn unit_level<'a>(number: isize, options: Option<Options<'a>>) -> Option<(u32, &'a str)> {
+    let options = options.unwrap_or_default();
+    let (_, parts) = fitted_parts(&options, |options| integer_parts(number, options, "")).ok()?;
+
+    Some((parts.level, parts.unit))
+}
+
+/// Abbreviates the given number according to specified options, along with the divisor
+/// that the number was scaled down by.
+///
+/// The divisor is `1` for numbers that are not abbreviated, and a power of ten in
+/// scientific notation. This is useful to scale other values into the same unit, such as
+/// the intermediate values of an animated counter.
+///
+/// # Arguments
+///
+/// * `number` - The integer to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some((value, divisor))`, a string representation of the abbreviated form of the
+/// number and its divisor. Returns `None` if the number is out of bounds or cannot be
+/// abbreviated using the provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev_with_scale;
+///
+/// assert_eq!(
+///     abbrev_with_scale(1_500_000, None),
+///     Some(("1.5M".to_string(), 1_000_000))
+/// );
+/// assert_eq!(abbrev_with_scale(150, None), Some(("150".to_string(), 1)));
+/// ```
+pub fn abbrev_with_scale(number: isize, options: Option<Options>) -> Option<(String, u128)> {
+    let options = options.unwrap_or_default();
+    let (options, parts) =
+        fitted_parts(&options, |options| integer_parts(number, options, "")).ok()?;
+
+    Some((parts.to_string(&options), parts.divisor))
+}
+
+/// Divides the given integer by a fixed factor and formats the rounded result without a
+/// unit, such as for a table whose header reads "in thousands".
+///
+/// The result is written at face value like with [Options::assume_unit] set to an empty
+/// unit, so that all numbers of a table share the same scale.
+///
+/// # Arguments
+///
+/// * `number` - The integer to be scaled down.
+/// * `factor` - The factor to divide the number by, which must not be zero.
+/// * `options` - An optional parameter specifying the [Options] for formatting.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the scaled number. Returns `None` if the
+/// factor is zero or the scaled number cannot be represented as a decimal.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev_scaled;
+///
+/// assert_eq!(abbrev_scaled(1_500_000, 1_000, None), Some("1500".to_string()));
+/// assert_eq!(abbrev_scaled(-1_250, 1_000, None), Some("-1.2".to_string()));
+/// ```
+pub fn abbrev_scaled(
+    number: impl Integer,
+    factor: u128,
+    options: Option<Options>,
+) -> Option<String> {
+    let (negative, absolute) = number.to_magnitude();
+
+    if factor == 0 || absolute / factor > Decimal::MAX.mantissa() as u128 {
+        return None;
+    }
+
+    let result = mantissa(absolute, factor);
+    let result = if negative { -result } else { result };
+    let options = Options {
+        assume_unit: Some(""),
+        ..options.unwrap_or_default()
+    };
+    let (options, parts) =
+        fitted_parts(&options, |options| decimal_parts(result, options, "")).ok()?;
+
+    Some(parts.to_string(&options))
+}
+
+/// Resolves the parts of a number at the highest precision that fits in
+/// [Options::max_width] and [Options::mantissa_width], returning the options that the
+/// parts are written with.
+pub(crate) fn fitted_parts<'a>(
+    options: &Options<'a>,
+    resolve: impl Fn(&Options<'a>) -> Result<Parts<'a>, AbbrevError>,
+) -> Result<(Options<'a>, Parts<'a>), AbbrevError> {
+    let Some(width) = options.max_width.max(options.mantissa_width) else {
+        return Ok((*options, resolve(options)?));
+    };
+
+    // A decimal never has more than 28 decimal places.
+    let mut precision = options
+        .max_precision
+        .or(options.precision)
+        .unwrap_or(width as u32)
+        .min(28);
+
+    loop {
+        let options = Options {
+            precision: Some(precision),
+            max_precision: options.max_precision.map(|_| precision),
+            precision_by_level: None,
+            ..*options
+        };
+        let parts = resolve(&options)?;
+        // Positive numbers are measured with a sign too, so that numbers of either sign
+        // are written at the same precision.
+        let signed = Parts {
+            negative: true,
+            ..parts
+        };
+
+        let fits = options
+            .max_width
+            .is_none_or(|max_width| signed.width(&options) <= max_width)
+            && options
+                .mantissa_width
+                .is_none_or(|mantissa_width| parts.mantissa_width(&options) <= mantissa_width);
+
+        if precision == 0 || fits {
+            return Ok((options, parts));
+        }
+
+        precision -= 1;
+    }
+}
+
+/// Resolves the parts of an abbreviated integer, appending the given suffix to its
+/// abbreviation unit.
+fn integer_parts<'a>(
+    number: impl Integer,
+    options: &Options<'a>,
+    unit_suffix: &'a str,
+) -> Result<Parts<'a>, AbbrevError> {
+    let (negative, absolute) = number.to_magnitude();
+
+    magnitude_parts(negative, absolute, options, unit_suffix)
+}
+
+/// Resolves the parts of an abbreviated integer from its sign and absolute value.
+fn magnitude_parts<'a>(
+    negative: bool,
+    absolute: u128,
+    options: &Options<'a>,
+    unit_suffix: &'a str,
+) -> Result<Parts<'a>, AbbrevError> {
+    if options.max_magnitude.is_some_and(|max| absolute > max) {
+        return Err(AbbrevError::AboveMaxMagnitude);
+    }
+
+    if let Some(cap) = options.saturate_at.filter(|cap| absolute > *cap) {
+        let options = Options {
+            saturate_at: None,
+            ..*options
+        };
+        let parts = magnitude_parts(negative, cap, &options, unit_suffix)?;
+
+        return Ok(Parts {
+            saturated: true,
+            ..parts
+        });
+    }
+
+    if let Some(unit) = options.assume_unit {
+        let mantissa = match absolute {
+            0 => Mantissa::Zero,
+            _ => Mantissa::Integer(absolute),
+        };
+
+        return Ok(assumed_parts(negative, mantissa, unit, unit_suffix));
+    }
+
+    if let Some(parts) = exact_parts(negative, absolute, options, unit_suffix) {
+        return Ok(parts);
+    }
+
+    if absolute <= Decimal::MAX.mantissa() as u128 {
+        let number = Decimal::from_i128_with_scale(absolute as i128, 0);
+        let number = if negative { -number } else { number };
+
+        return decimal_parts(number, options, unit_suffix);
+    }
+
+    let options = &signed_options(options, negative);
+
+    // Wider integers do not fit in a decimal, so only their mantissa is scaled down.
+    match options.notation {
+        NotationMode::Compact => {}
+        NotationMode::Standard => {
+            return Ok(Parts {
+                negative,
+                mantissa: Mantissa::Integer(absolute),
+                unit: unit(0, &Mantissa::Integer(absolute), options)?,
+                unit_suffix,
+                exponent: None,
+                divisor: 1,
+                precision: None,
+                saturated: false,
+                spaced_suffix: false,
+                level: 0,
+                places: None,
+            });
+        }
+        NotationMode::Scientific => {
+            return Ok(scientific_parts(
+                negative,
+                absolute,
+                1,
+                options,
+                unit_suffix,
+            ));
+        }
+        NotationMode::Engineering => {
+            return Ok(scientific_parts(
+                negative,
+                absolute,
+                3,
+                options,
+                unit_suffix,
+            ));
+        }
+    }
+
+    if let Some(level) = fixed_level(options)? {
+        let divisor = divisor(level, options);
+
+        // The mantissa of a wide integer must still fit in a decimal.
+        if absolute / divisor > Decimal::MAX.mantissa() as u128 {
+            return Err(AbbrevError::ConversionFailed);
+        }
+
+        return fixed_parts(
+            negative,
+            mantissa(absolute, divisor),
+            level,
+            options,
+            unit_suffix,
+        );
+    }
+
+    if let Some(scale) = options.unit_scale {
+        let (result, unit, divisor, level) = scaled(
+            scale,
+            absolute,
+            |threshold| {
+                // The mantissa of a wide integer must still fit in a decimal.
+                if absolute / threshold > Decimal::MAX.mantissa() as u128 {
+                    Err(AbbrevError::ConversionFailed)
+                } else {
+                    Ok(mantissa(absolute, threshold))
+                }
+            },
+            options,
+        )?;
+
+        return Ok(Parts {
+            negative,
+            mantissa: Mantissa::Decimal(result.normalize()),
+            unit,
+            unit_suffix,
+            exponent: None,
+            divisor,
+            precision: None,
+            saturated: false,
+            spaced_suffix: false,
+            level,
+            places: None,
+        });
+    }
+
+    let level = level(absolute, options)?;
+    let (mantissa, level) = if level == 0 {
+        (Mantissa::Integer(absolute), level)
+    } else {
+        let result = mantissa(absolute, divisor(level, options));
+        let (result, level) = carry(
+            round(result, &level_options(level, options)),
+            level,
+            options,
+        )?;
+        (Mantissa::Decimal(result.normalize()), level)
+    };
+
+    let unit = match unit(level, &mantissa, options) {
+        Err(AbbrevError::MagnitudeOutOfRange { .. }) if options.scientific_fallback => {
+            return Ok(scientific_parts(
+                negative,
+                absolute,
+                1,
+                options,
+                unit_suffix,
+            ));
+        }
+        Err(AbbrevError::MagnitudeOutOfRange { .. }) if options.clamp_to_max_unit => {
+            let level = max_level(options)?;
+            let divisor = divisor(level, options);
+
+            // The mantissa of a wide integer must still fit in a decimal.
+            if absolute / divisor > Decimal::MAX.mantissa() as u128 {
+                return Err(AbbrevError::ConversionFailed);
+            }
+
+            return fixed_parts(
+                negative,
+                self::mantissa(absolute, divisor),
+                level,
+                options,
+                unit_suffix,
+            );
+        }
+        unit => unit?,
+    };
+
+    Ok(Parts {
+        negative,
+        mantissa,
+        unit,
+        unit_suffix,
+        exponent: None,
+        divisor: divisor(level, options),
+        precision: level_options(level, options).precision,
+        saturated: false,
+        spaced_suffix: false,
+        level,
+        places: None,
+    })
+}
+
+/// Resolves the parts of a non-zero absolute integer that is an exact multiple of the
+/// divisor of its level, such as `2_000`, without any decimal arithmetic.
+///
+/// Such a mantissa needs no rounding, so this is a faster path to the same parts for
+/// common round numbers. `None` is returned whenever the full path is needed.
+fn exact_parts<'a>(
+    negative: bool,
+    absolute: u128,
+    options: &Options<'a>,
+    unit_suffix: &'a str,
+) -> Option<Parts<'a>> {
+    if absolute == 0
+        || options.notation != NotationMode::Compact
+        || options.fixed_unit.is_some()
+        || options.unit_scale.is_some()
+        || options.significant_digits.is_some()
+        || options.nice_numbers
+        || options.clamp_precision
+    {
+        return None;
+    }
+
+    let level = level(absolute, options).ok()?;
+    let divisor = divisor(level, options);
+
+    if !absolute.is_multiple_of(divisor) {
+        return None;
+    }
+
+    let mantissa = Mantissa::Integer(absolute / divisor);
+
+    Some(Parts {
+        negative,
+        mantissa,
+        unit: unit(level, &mantissa, options).ok()?,
+        unit_suffix,
+        exponent: None,
+        divisor,
+        precision: level_options(level, options).precision,
+        saturated: false,
+        spaced_suffix: false,
+        level,
+        places: None,
+    })
+}
+
+/// Resolves the parts of an abbreviated floating-point number.
+fn float_parts<'a>(number: f64, options: &Options<'a>) -> Result<Parts<'a>, AbbrevError> {
+    if !number.is_finite() {
+        return Err(AbbrevError::NotFinite);
+    }
+
+    converted_float_parts(number, Decimal::from_f64(number), options)
+}
+
+/// Resolves the parts of an abbreviated single-precision floating-point number.
+fn f32_parts<'a>(number: f32, options: &Options<'a>) -> Result<Parts<'a>, AbbrevError> {
+    if !number.is_finite() {
+        return Err(AbbrevError::NotFinite);
+    }
+
+    converted_float_parts(number, Decimal::from_f32(number), options)
+}
+
+/// Resolves the parts of a finite float from its conversion to a decimal. Floats that a
+/// decimal cannot hold, or only holds as zero, are written with the power of ten of the
+/// float itself when [Options::notation] or [Options::scientific_fallback] allows it.
+fn converted_float_parts<'a, F>(
+    number: F,
+    decimal: Option<Decimal>,
+    options: &Options<'a>,
+) -> Result<Parts<'a>, AbbrevError>
+where
+    F: fmt::LowerExp + Into<f64> + Copy,
+{
+    let float: f64 = number.into();
+    let overflow = decimal.is_none();
+    let underflow = decimal.is_some_and(|decimal| decimal.is_zero()) && float != 0.0;
+
+    let step = match options.notation {
+        _ if options.assume_unit.is_some()
+            || options.fixed_unit.is_some()
+            || options.saturate_at.is_some() =>
+        {
+            None
+        }
+        NotationMode::Scientific => Some(1),
+        NotationMode::Engineering => Some(3),
+        NotationMode::Compact if overflow && options.scientific_fallback => Some(1),
+        _ => None,
+    };
+
+    match (decimal, step) {
+        (Some(decimal), _) if !underflow => decimal_parts(decimal, options, ""),
+        (_, Some(_))
+            if options
+                .max_magnitude
+                .is_some_and(|max| float.abs() > max as f64) =>
+        {
+            Err(AbbrevError::AboveMaxMagnitude)
+        }
+        (_, Some(step)) => float_exponent_parts(number, float < 0.0, step, options),
+        // Without an exponent, a float below the smallest decimal is written as zero.
+        (Some(decimal), None) => decimal_parts(decimal, options, ""),
+        (None, None) => Err(AbbrevError::ConversionFailed),
+    }
+}
+
+/// Resolves the parts of a non-zero finite float written with a power of ten that is a
+/// multiple of the given step. The exponent and the mantissa in the range of `1` to `10`
+/// are read from the shortest exponent form of the float, such as `1.5e-30`, so that
+/// they are exact for any float.
+fn float_exponent_parts<'a>(
+    number: impl fmt::LowerExp,
+    negative: bool,
+    step: u32,
+    options: &Options<'a>,
+) -> Result<Parts<'a>, AbbrevError> {
+    let options = &signed_options(options, negative);
+    let mut buffer = Buffer::default();
+    fmt::Write::write_fmt(&mut buffer, format_args!("{number:e}"))?;
+
+    let (mantissa, exponent) = buffer
+        .as_str()
+        .trim_start_matches('-')
+        .split_once('e')
+        .ok_or(AbbrevError::ConversionFailed)?;
+    let mantissa = mantissa
+        .parse::<Decimal>()
+        .map_err(|_| AbbrevError::ConversionFailed)?;
+    let exponent = exponent
+        .parse::<i32>()
+        .map_err(|_| AbbrevError::ConversionFailed)?;
+
+    let aligned = exponent.div_euclid(step as i32) * step as i32;
+    let limit = shift(Decimal::ONE, step as i32)?;
+    let mut result = round(shift(mantissa, exponent - aligned)?, options);
+    let mut exponent = aligned;
+
+    if result >= limit {
+        result = round(result / limit, options);
+        exponent += step as i32;
+    }
+
+    Ok(Parts {
+        negative,
+        mantissa: Mantissa::Decimal(result.normalize()),
+        unit: "",
+        unit_suffix: "",
+        exponent: Some(exponent),
+        divisor: u32::try_from(exponent).map_or(1, |exponent| 10_u128.saturating_pow(exponent)),
+        precision: None,
+        saturated: false,
+        spaced_suffix: false,
+        level: 0,
+        places: None,
+    })
+}
+
+/// Resolves the parts of an abbreviated decimal, appending the given suffix to its
+/// abbreviation unit.
+fn decimal_parts<'a>(
+    number: Decimal,
+    options: &Options<'a>,
+    unit_suffix: &'a str,
+) -> Result<Parts<'a>, AbbrevError> {
+    if options.clamp_precision {
+        return clamped_parts(number, options, unit_suffix);
+    }
+
+    if options
+        .max_magnitude
+        .and_then(Decimal::from_u128)
+        .is_some_and(|max| number.abs() > max)
+    {
+        return Err(AbbrevError::AboveMaxMagnitude);
+    }
+
+    if let Some(cap) = options
+        .saturate_at
+        .and_then(Decimal::from_u128)
+        .filter(|cap| number.abs() > *cap)
+    {
+        let options = Options {
+            saturate_at: None,
+            ..*options
+        };
+        let cap = if number.is_sign_negative() { -cap } else { cap };
+        let parts = decimal_parts(cap, &options, unit_suffix)?;
+
+        return Ok(Parts {
+            saturated: true,
+            ..parts
+        });
+    }
+
+    let options = &signed_options(options, number.is_sign_negative());
+
+    if let Some(unit) = options.assume_unit {
+        let result = round(number.abs(), options);
+        let mantissa = if result.is_zero() {
+            Mantissa::Zero
+        } else {
+            Mantissa::Decimal(result.normalize())
+        };
+
+        return Ok(assumed_parts(
+            number.is_sign_negative(),
+            mantissa,
+            unit,
+            unit_suffix,
+        ));
+    }
+
+    match options.notation {
+        NotationMode::Compact => {}
+        NotationMode::Standard => return standard_parts(number, options, unit_suffix),
+        NotationMode::Scientific => return exponent_parts(number, 1, options, unit_suffix),
+        NotationMode::Engineering => return exponent_parts(number, 3, options, unit_suffix),
+    }
+
+    let absolute = number.abs();
+
+    if let Some(level) = fixed_level(options)? {
+        let divisor = decimal_divisor(level, options)?;
+        let negative = number.is_sign_negative();
+
+        return fixed_parts(negative, absolute / divisor, level, options, unit_suffix);
+    }
+
+    let (result, unit, divisor, precision, level) = match (options.small_units, options.unit_scale)
+    {
+        (Some(units), _) if absolute < Decimal::ONE && !absolute.is_zero() => {
+            let (result, level) = small_mantissa(absolute, options)?;
+            let base = Decimal::from_u128(base(options)?).ok_or(AbbrevError::ConversionFailed)?;
+            let result = round(result, options);
+
+            // A mantissa rounded up to the base belongs to the next larger unit.
+            let (result, level) = if result >= base {
+                (round(result / base, options), level - 1)
+            } else {
+                (result, level)
+            };
+
+            let unit = match level {
+                0 => unit(0, &Mantissa::Decimal(result), options)?,
+                _ => units
+                    .get(level as usize - 1)
+                    .ok_or(AbbrevError::MagnitudeOutOfRange {
+                        level: -(level as i32),
+                    })?,
+            };
+            (result, unit, 1, None, 0)
+        }
+        (_, Some(scale)) => {
+            let integer = absolute.trunc().mantissa() as u128;
+            let (result, unit, divisor, level) = scaled(
+                scale,
+                integer,
+                |threshold| {
+                    Decimal::from_u128(threshold)
+                        .map(|threshold| absolute / threshold)
+                        .ok_or(AbbrevError::ConversionFailed)
+                },
+                options,
+            )?;
+
+            (result, unit, divisor, None, level)
+        }
+        _ => {
+            let level = level(absolute.trunc().mantissa() as u128, options)?;
+            let scale = decimal_divisor(level, options)?;
+            let result = round(absolute / scale, &level_options(level, options));
+            let (result, level) = carry(result, level, options)?;
+
+            match unit(level, &Mantissa::Decimal(result), options) {
+                Err(AbbrevError::MagnitudeOutOfRange { .. }) if options.scientific_fallback => {
+                    let integer = absolute
+                        .trunc()
+                        .to_u128()
+                        .ok_or(AbbrevError::ConversionFailed)?;
+                    let negative = number.is_sign_negative();
+                    return Ok(scientific_parts(negative, integer, 1, options, unit_suffix));
+                }
+                Err(AbbrevError::MagnitudeOutOfRange { .. }) if options.clamp_to_max_unit => {
+                    let level = max_level(options)?;
+                    let divisor = decimal_divisor(level, options)?;
+                    let negative = number.is_sign_negative();
+                    return fixed_parts(negative, absolute / divisor, level, options, unit_suffix);
+                }
+                unit => (
+                    result,
+                    unit?,
+                    divisor(level, options),
+                    level_options(level, options).precision,
+                    level,
+                ),
+            }
+        }
+    };
+
+    if result.is_zero() {
+        return zero_parts(options, unit_suffix);
+    }
+
+    Ok(Parts {
+        negative: number.is_sign_negative(),
+        mantissa: Mantissa::Decimal(result.normalize()),
+        unit,
+        unit_suffix,
+        exponent: None,
+        divisor,
+        precision,
+        saturated: false,
+        spaced_suffix: false,
+        level,
+        places: None,
+    })
+}
+
+/// Resolves the parts of an abbreviated decimal with its precision capped at the decimal
+/// places that its resolution supports, as with [Options::clamp_precision].
+fn clamped_parts<'a>(
+    number: Decimal,
+    options: &Options<'a>,
+    unit_suffix: &'a str,
+) -> Result<Parts<'a>, AbbrevError> {
+    let options = Options {
+        clamp_precision: false,
+        ..*options
+    };
+    let parts = decimal_parts(number, &options, unit_suffix)?;
+    // Scaling down by a divisor moves the last digit of the number that many places right.
+    let places = number.normalize().scale() + parts.divisor.checked_ilog10().unwrap_or(0);
+    let precision = parts
+        .precision
+        .or(options.max_precision)
+        .or(options.precision)
+        .unwrap_or(1);
+
+    let parts = if places < precision {
+        let options = Options {
+            precision: Some(places),
+            max_precision: options.max_precision.map(|max| max.min(places)),
+            precision_by_level: None,
+            ..options
+        };
+
+        decimal_parts(number, &options, unit_suffix)?
+    } else {
+        parts
+    };
+
+    Ok(Parts {
+        places: Some(places),
+        ..parts
+    })
+}
+
+/// Returns the rounded mantissa, unit, threshold and level of the highest breakpoint of
+/// the scale that does not exceed the integer part of a value, where the first
+/// breakpoint is at level `1`.
+///
+/// The mantissa is computed by the given function from the threshold of a breakpoint.
+/// A mantissa rounded up to the next threshold is promoted to its breakpoint, unless
+/// [Options::no_unit_promotion] is set.
+fn scaled<'a>(
+    scale: &'a [(u128, &'a str)],
+    integer: u128,
+    divide: impl Fn(u128) -> Result<Decimal, AbbrevError>,
+    options: &Options,
+) -> Result<(Decimal, &'a str, u128, u32), AbbrevError> {
+    // The thresholds are ascending, so the highest one not exceeding the integer is found
+    // with a binary search.
+    let Some(index) = scale
+        .partition_point(|(threshold, _)| *threshold <= integer)
+        .checked_sub(1)
+    else {
+        return Ok((round(divide(1)?, options), "", 1, 0));
+    };
+
+    let (threshold, unit) = scale[index];
+    let threshold = threshold.max(1);
+    let result = round(divide(threshold)?, options);
+
+    match scale.get(index + 1) {
+        Some((next, unit))
+            if !options.no_unit_promotion
+                && Decimal::from_u128(threshold)
+                    .and_then(|threshold| result.checked_mul(threshold))
+                    .zip(Decimal::from_u128(*next))
+                    .is_some_and(|(rounded, next)| rounded >= next) =>
+        {
+            Ok((
+                round(divide(*next)?, options),
+                unit,
+                *next,
+                index as u32 + 2,
+            ))
+        }
+        _ => Ok((result, unit, threshold, index as u32 + 1)),
+    }
+}
+
+/// Returns the level of [Options::fixed_unit] among the abbreviation units, if set.
+fn fixed_level(options: &Options) -> Result<Option<u32>, AbbrevError> {
+    let Some(fixed) = options.fixed_unit else {
+        return Ok(None);
+    };
+
+    abbreviations(options)
+        .iter()
+        .position(|abbreviation| *abbreviation == fixed)
+        .map(|index| Some(index as u32 + first_level(options)))
+        .ok_or(AbbrevError::UnknownUnit)
+}
+
+/// Resolves the parts of a mantissa written in the fixed unit at the given level, even
+/// when it rounds to zero.
+fn fixed_parts<'a>(
+    negative: bool,
+    mantissa: Decimal,
+    level: u32,
+    options: &Options<'a>,
+    unit_suffix: &'a str,
+) -> Result<Parts<'a>, AbbrevError> {
+    let leveled = level_options(level, options);
+    let result = round(mantissa, &leveled);
+    let mantissa = if result.is_zero() {
+        Mantissa::Zero
+    } else {
+        Mantissa::Decimal(result.normalize())
+    };
+
+    Ok(Parts {
+        negative,
+        mantissa,
+        unit: unit(level, &mantissa, options)?,
+        unit_suffix,
+        exponent: None,
+        divisor: divisor(level, options),
+        precision: leveled.precision,
+        saturated: false,
+        spaced_suffix: false,
+        level,
+        places: None,
+    })
+}
+
+/// Resolves the parts of a zero value, which is written with the first unit.
+fn zero_parts<'a>(options: &Options<'a>, unit_suffix: &'a str) -> Result<Parts<'a>, AbbrevError> {
+    Ok(Parts {
+        negative: false,
+        mantissa: Mantissa::Zero,
+        unit: unit(0, &Mantissa::Zero, options)?,
+        unit_suffix,
+        exponent: None,
+        divisor: 1,
+        precision: None,
+        saturated: false,
+        spaced_suffix: false,
+        level: 0,
+        places: None,
+    })
+}
+
+/// Returns the options with the rounding strategy for the sign of the number.
+fn signed_options<'a>(options: &Options<'a>, negative: bool) -> Options<'a> {
+    match options.negative_rounding_strategy {
+        Some(strategy) if negative => Options {
+            rounding_strategy: Some(strategy),
+            ..*options
+        },
+        _ => *options,
+    }
+}
+
+/// Resolves the parts of a mantissa written at face value in [Options::assume_unit].
+fn assumed_parts<'a>(
+    negative: bool,
+    mantissa: Mantissa,
+    unit: &'a str,
+    unit_suffix: &'a str,
+) -> Parts<'a> {
+    Parts {
+        negative,
+        mantissa,
+        unit,
+        unit_suffix,
+        exponent: None,
+        divisor: 1,
+        precision: None,
+        saturated: false,
+        spaced_suffix: false,
+        level: 0,
+        places: None,
+    }
+}
+
+/// Resolves the parts of a decimal written in full without a unit.
+fn standard_parts<'a>(
+    number: Decimal,
+    options: &Options<'a>,
+    unit_suffix: &'a str,
+) -> Result<Parts<'a>, AbbrevError> {
+    let result = round(number.abs(), options);
+
+    if result.is_zero() {
+        return zero_parts(options, unit_suffix);
+    }
+
+    Ok(Parts {
+        negative: number.is_sign_negative(),
+        mantissa: Mantissa::Decimal(result.normalize()),
+        unit: unit(0, &Mantissa::Decimal(result), options)?,
+        unit_suffix,
+        exponent: None,
+        divisor: 1,
+        precision: None,
+        saturated: false,
+        spaced_suffix: false,
+        level: 0,
+        places: None,
+    })
+}
+
+/// Resolves the parts of a decimal written with a power of ten that is a multiple of
+/// the given step.
+fn exponent_parts<'a>(
+    number: Decimal,
+    step: u32,
+    options: &Options<'a>,
+    unit_suffix: &'a str,
+) -> Result<Parts<'a>, AbbrevError> {
+    let absolute = number.abs();
+
+    if absolute.is_zero() {
+        return zero_parts(options, unit_suffix);
+    }
+
+    let mut exponent = if absolute >= Decimal::ONE {
+        let integer = absolute
+            .trunc()
+            .to_u128()
+            .ok_or(AbbrevError::ConversionFailed)?;
+        integer.ilog10() as i32
+    } else {
+        let mut value = absolute;
+        let mut exponent = 0;
+
+        while value < Decimal::ONE {
+            value *= Decimal::TEN;
+            exponent -= 1;
+        }
+
+        exponent
+    };
+    exponent = exponent.div_euclid(step as i32) * step as i32;
+
+    let limit = shift(Decimal::ONE, step as i32)?;
+    let mut result = round(shift(absolute, -exponent)?, options);
+
+    if result >= limit {
+        result = round(result / limit, options);
+        exponent += step as i32;
+    }
+
+    Ok(Parts {
+        negative: number.is_sign_negative(),
+        mantissa: Mantissa::Decimal(result.normalize()),
+        unit: "",
+        unit_suffix,
+        exponent: Some(exponent),
+        divisor: u32::try_from(exponent).map_or(1, |exponent| 10_u128.saturating_pow(exponent)),
+        precision: None,
+        saturated: false,
+        spaced_suffix: false,
+        level: 0,
+        places: None,
+    })
+}
+
+/// Returns the value multiplied by ten to the power of the given number of digits.
+fn shift(value: Decimal, digits: i32) -> Result<Decimal, AbbrevError> {
+    let factor = if digits >= 0 {
+        10_i128
+            .checked_pow(digits as u32)
+            .map(|factor| Decimal::from_i128_with_scale(factor, 0))
+    } else {
+        Decimal::try_from_i128_with_scale(1, digits.unsigned_abs()).ok()
+    };
+
+    factor
+        .and_then(|factor| value.checked_mul(factor))
+        .ok_or(AbbrevError::ConversionFailed)
+}
+
+/// Resolves the parts of a non-zero absolute integer written with a power of ten that
+/// is a multiple of the given step, used for integers that do not fit in a decimal and
+/// for those beyond the last abbreviation unit.
+fn scientific_parts<'a>(
+    negative: bool,
+    absolute: u128,
+    step: u32,
+    options: &Options<'a>,
+    unit_suffix: &'a str,
+) -> Parts<'a> {
+    let exponent = absolute.ilog10() / step * step;
+    let limit = Decimal::from(10_u128.pow(step));
+    let mut result = round(mantissa(absolute, 10_u128.pow(exponent)), options);
+    let mut exponent = exponent as i32;
+
+    if result >= limit {
+        result = round(result / limit, options);
+        exponent += step as i32;
+    }
+
+    Parts {
+        negative,
+        mantissa: Mantissa::Decimal(result.normalize()),
+        unit: "",
+        unit_suffix,
+        exponent: Some(exponent),
+        divisor: 10_u128.saturating_pow(exponent as u32),
+        precision: None,
+        saturated: false,
+        spaced_suffix: false,
+        level: 0,
+        places: None,
+    }
+}
+
+/// Returns the base between consecutive abbreviation units.
+pub(crate) fn base(options: &Options) -> Result<u128, AbbrevError> {
+    let base = match options.base {
+        Some(base) => base as u128,
+        None => 10_u128
+            .checked_pow(options.step.unwrap_or(3))
+            .ok_or(AbbrevError::InvalidBase)?,
+    };
+
+    if base >= 2 {
+        Ok(base)
+    } else {
+        Err(AbbrevError::InvalidBase)
+    }
+}
+
+/// Returns the level of abbreviation for the integer part of an absolute value.
+fn level(integer: u128, options: &Options) -> Result<u32, AbbrevError> {
+    let base = base(options)?;
+    let threshold = options.min_abbrev_value.unwrap_or(0) as u128;
+
+    if integer == 0 || integer < threshold {
+        Ok(0)
+    } else if let Some(table) = scale_table(base, options) {
+        Ok(table.level(integer))
+    } else {
+        Ok(integer.ilog(base))
+    }
+}
+
+/// Returns the divisor that scales a value down to the mantissa of the given level.
+fn divisor(level: u32, options: &Options) -> u128 {
+    let Ok(base) = base(options) else {
+        return 1;
+    };
+
+    match scale_table(base, options) {
+        Some(table) => table.divisor(level),
+        None => base.saturating_pow(level),
+    }
+}
+
+/// Returns the divisor of the given level as a decimal.
+fn decimal_divisor(level: u32, options: &Options) -> Result<Decimal, AbbrevError> {
+    match base(options).map(|base| scale_table(base, options)) {
+        Ok(Some(table)) => table.decimal_divisor(level),
+        _ => Decimal::from_u128(divisor(level, options)).ok_or(AbbrevError::ConversionFailed),
+    }
+}
+
+/// Returns [Options::scale_table] if it was computed for the given base.
+fn scale_table<'a>(base: u128, options: &Options<'a>) -> Option<&'a ScaleTable> {
+    options.scale_table.filter(|table| table.base() == base)
+}
+
+/// Returns the abbreviation units to use for the given options, starting at
+/// [Options::base_level_offset].
+pub(crate) fn abbreviations<'a>(options: &Options<'a>) -> &'a [&'a str] {
+    let units = options
+        .abbreviations
+        .unwrap_or(match (options.long_words, options.scale) {
+            (true, NumberScale::Short) => &LONG_ABBREVIATIONS[..],
+            (true, NumberScale::Long) => &LONG_SCALE_ABBREVIATIONS[..],
+            (false, _) => &DEFAULT_ABBREVIATIONS,
+        });
+
+    offset_units(units, options)
+}
+
+/// Returns the lowest level with an abbreviation unit, which is above `0` when
+/// [Options::base_level_offset] is negative.
+pub(crate) fn first_level(options: &Options) -> u32 {
+    options
+        .base_level_offset
+        .map_or(0, |offset| offset.min(0).unsigned_abs())
+}
+
+/// Returns the index of the abbreviation unit for the given level, if the level is not
+/// below [first_level].
+fn unit_index(level: u32, options: &Options) -> Option<usize> {
+    level
+        .checked_sub(first_level(options))
+        .map(|index| index as usize)
+}
+
+/// Returns [Options::long_words_plural] starting at [Options::base_level_offset].
+pub(crate) fn plurals<'a>(options: &Options<'a>) -> Option<&'a [&'a str]> {
+    options
+        .long_words_plural
+        .map(|plurals| offset_units(plurals, options))
+}
+
+/// Skips the units below a positive [Options::base_level_offset], leaving none if the
+/// offset is beyond the last unit. A negative offset skips no units, but moves the first
+/// of them up to [first_level].
+fn offset_units<'a>(units: &'a [&'a str], options: &Options) -> &'a [&'a str] {
+    let offset = options.base_level_offset.unwrap_or(0).max(0) as usize;
+
+    units.get(offset..).unwrap_or_default()
+}
+
+/// Returns the level of the last abbreviation unit.
+pub(crate) fn max_level(options: &Options) -> Result<u32, AbbrevError> {
+    let count = abbreviations(options).len() as u32;
+
+    count
+        .checked_sub(1)
+        .map(|index| index + first_level(options))
+        .ok_or(AbbrevError::MagnitudeOutOfRange { level: 0 })
+}
+
+/// Returns the abbreviation unit for the given level.
+fn abbreviation<'a>(level: u32, options: &Options<'a>) -> Result<&'a str, AbbrevError> {
+    unit_index(level, options)
+        .and_then(|index| abbreviations(options).get(index))
+        .copied()
+        .ok_or(AbbrevError::MagnitudeOutOfRange {
+            level: level as i32,
+        })
+}
+
+/// Returns the abbreviation unit for the given level, in its plural form from
+/// [Options::long_words_plural] unless the mantissa is exactly one.
+fn unit<'a>(
+    level: u32,
+    mantissa: &Mantissa,
+    options: &Options<'a>,
+) -> Result<&'a str, AbbrevError> {
+    let singular = abbreviation(level, options)?;
+    let one = match mantissa {
+        Mantissa::Zero => false,
+        Mantissa::Integer(value) => *value == 1,
+        Mantissa::Decimal(value) => *value == Decimal::ONE,
+    };
+
+    match plurals(options) {
+        Some(plurals) if !one => Ok(unit_index(level, options)
+            .and_then(|index| plurals.get(index))
+            .copied()
+            .unwrap_or(singular)),
+        _ => Ok(singular),
+    }
+}
+
+/// Returns the mantissa of an absolute integer value divided by the given divisor.
+///
+/// [Decimal] can only hold 96 bits, so trailing digits of both values that do not fit
+/// are dropped. These are far beyond any meaningful precision.
+fn mantissa(absolute: u128, divisor: u128) -> Decimal {
+    let mut absolute = absolute;
+    let mut divisor = divisor;
+
+    while absolute > Decimal::MAX.mantissa() as u128 {
+        absolute /= 10;
+        divisor /= 10;
+    }
+
+    Decimal::from_i128_with_scale(absolute as i128, 0)
+        / Decimal::from_i128_with_scale(divisor as i128, 0)
+}
+
+/// Returns the mantissa of a non-zero absolute value below one along with its level
+/// among the small units, starting at `1`.
+fn small_mantissa(absolute: Decimal, options: &Options) -> Result<(Decimal, u32), AbbrevError> {
+    let base = Decimal::from_u128(base(options)?).ok_or(AbbrevError::ConversionFailed)?;
+    let mut mantissa = absolute;
+    let mut level = 0;
+
+    while mantissa < Decimal::ONE {
+        mantissa = mantissa
+            .checked_mul(base)
+            .ok_or(AbbrevError::ConversionFailed)?;
+        level += 1;
+    }
+
+    Ok((mantissa, level))
+}
+
+/// Promotes a rounded mantissa that reached the base to the next abbreviation unit,
+/// returning the mantissa along with its possibly bumped level.
+///
+/// The level is only checked against the abbreviation units once the unit is looked
+/// up, so promoting past the last unit results in an out-of-range error.
+fn carry(mantissa: Decimal, level: u32, options: &Options) -> Result<(Decimal, u32), AbbrevError> {
+    if options.no_unit_promotion {
+        return Ok((mantissa, level));
+    }
+
+    if level == 0 {
+        // Unabbreviated values are re-leveled, as they may be held back by a threshold.
+        let integer = mantissa
+            .trunc()
+            .to_u128()
+            .ok_or(AbbrevError::ConversionFailed)?;
+        let promoted = self::level(integer, options)?;
+
+        if promoted == 0 {
+            return Ok((mantissa, level));
+        }
+
+        let divisor = decimal_divisor(promoted, options)?;
+
+        let result = round(mantissa / divisor, &level_options(promoted, options));
+        return Ok((result, promoted));
+    }
+
+    let base = Decimal::from_u128(base(options)?).ok_or(AbbrevError::ConversionFailed)?;
+
+    if mantissa < base {
+        Ok((mantissa, level))
+    } else {
+        let result = round(mantissa / base, &level_options(level + 1, options));
+        Ok((result, level + 1))
+    }
+}
+
+/// Returns the options with the precision from [Options::precision_by_level] for the
+/// given level, if it covers the level.
+pub(crate) fn level_options<'a>(level: u32, options: &Options<'a>) -> Options<'a> {
+    let precision = options
+        .precision_by_level
+        .and_then(|precisions| precisions.get(level as usize).copied());
+
+    Options {
+        precision: precision.or(options.precision),
+        ..*options
+    }
+}
+
+/// Rounds the mantissa according to the precision and rounding strategy.
+fn round(mantissa: Decimal, options: &Options) -> Decimal {
+    let strategy = if options.no_unit_promotion {
+        RoundingStrategy::ToZero
+    } else {
+        options
+            .rounding_strategy
+            .unwrap_or(RoundingStrategy::MidpointNearestEven)
+    };
+
+    if options.nice_numbers {
+        return round_nice(mantissa, options.no_unit_promotion);
+    }
+
+    match options.significant_digits {
+        Some(digits) => round_significant(mantissa, digits.max(1), strategy, options),
+        None => round_dp(
+            mantissa,
+            options.max_precision.or(options.precision).unwrap_or(1),
+            strategy,
+            options,
+        ),
+    }
+}
+
+/// Rounds the mantissa to the given number of decimal places, using
+/// [Options::round_threshold] in place of the strategy if set.
+fn round_dp(
+    mantissa: Decimal,
+    places: u32,
+    strategy: RoundingStrategy,
+    options: &Options,
+) -> Decimal {
+    let threshold = options
+        .round_threshold
+        .filter(|_| !options.no_unit_promotion);
+    let Some(threshold) = threshold else {
+        return mantissa.round_dp_with_strategy(places, strategy);
+    };
+
+    if places >= mantissa.scale() {
+        return mantissa;
+    }
+
+    let truncated = mantissa.round_dp_with_strategy(places, RoundingStrategy::ToZero);
+    let step = Decimal::new(1, places);
+
+    if ((mantissa - truncated) / step).abs() < threshold {
+        truncated
+    } else if mantissa.is_sign_negative() {
+        truncated - step
+    } else {
+        truncated + step
+    }
+}
+
+/// Snaps the mantissa to the nearest of `1`, `2`, `2.5`, `5` and `10` times the power of
+/// ten of its first digit, preferring the smaller value on ties, or to the largest one
+/// that does not exceed it if `floor` is set.
+fn round_nice(mantissa: Decimal, floor: bool) -> Decimal {
+    const NICE: [Decimal; 5] = [
+        Decimal::ONE,
+        Decimal::TWO,
+        Decimal::from_parts(25, 0, 0, false, 1),
+        Decimal::from_parts(5, 0, 0, false, 0),
+        Decimal::TEN,
+    ];
+
+    if mantissa.is_zero() {
+        return mantissa;
+    }
+
+    let Ok(factor) = shift(Decimal::ONE, magnitude(mantissa.abs())) else {
+        return mantissa;
+    };
+    let fraction = mantissa.abs() / factor;
+    let nice = if floor {
+        NICE.into_iter().rfind(|nice| *nice <= fraction)
+    } else {
+        NICE.into_iter().min_by_key(|nice| (fraction - nice).abs())
+    }
+    .unwrap_or(Decimal::ONE);
+    let result = nice.checked_mul(factor).unwrap_or(mantissa.abs());
+
+    if mantissa.is_sign_negative() {
+        -result
+    } else {
+        result
+    }
+}
+
+/// Returns the power of ten of the first digit of a non-zero absolute value.
+fn magnitude(absolute: Decimal) -> i32 {
+    match absolute.trunc().to_u128() {
+        Some(integer) if integer > 0 => integer.ilog10() as i32,
+        _ => {
+            let mut value = absolute;
+            let mut magnitude = 0;
+
+            while value < Decimal::ONE {
+                value *= Decimal::TEN;
+                magnitude -= 1;
+            }
+
+            magnitude
+        }
+    }
+}
+
+/// Rounds the mantissa to the given number of significant digits.
+fn round_significant(
+    mantissa: Decimal,
+    digits: u32,
+    strategy: RoundingStrategy,
+    options: &Options,
+) -> Decimal {
+    if mantissa.is_zero() {
+        return mantissa;
+    }
+
+    let places = digits as i32 - 1 - magnitude(mantissa.abs());
+
+    if places >= 0 {
+        return round_dp(mantissa, places as u32, strategy, options);
+    }
+
+    // Digits before the decimal point are rounded by scaling them past it first.
+    match shift(Decimal::ONE, -places) {
+        Ok(factor) => round_dp(mantissa / factor, 0, strategy, options) * factor,
+        Err(_) => mantissa,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_abbreviate_numbers() {
+        let fixtures: Vec<(isize, &str)> = vec![
+            (0, "0"),
+            (-0, "0"),
+            (1, "1"),
+            (10, "10"),
+            (150, "150"),
+            (999, "999"),
+            (1_000, "1k"),
+            (1_200, "1.2k"),
+            (10_000, "10k"),
+            (150_000, "150k"),
+            (1_000_000, "1M"),
+            (4_500_000, "4.5M"),
+            (-10, "-10"),
+            (-1_500, "-1.5k"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, None);
+            assert_eq!(result, Some(expected.to_string()));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_upto_a_certain_precision() {
+        let result = abbrev_num(
+            1_566_450,
+            Some(Options {
+                precision: Some(3),
+                ..Default::default()
+            }),
+        );
+        assert_eq!(result, Some("1.566M".to_string()));
+
+        // Zero precision
+        let result = abbrev_num(
+            1_566_450,
+            Some(Options {
+                precision: Some(0),
+                ..Default::default()
+            }),
+        );
+        assert_eq!(result, Some("2M".to_string()));
+    }
+
+    #[test]
+    fn can_round_exactly_using_rounding_strategies() {
+        let fixtures: Vec<(RoundingStrategy, &str)> = vec![
+            (RoundingStrategy::MidpointNearestEven, "1.234M"),
+            (RoundingStrategy::MidpointAwayFromZero, "1.235M"),
+            (RoundingStrategy::MidpointTowardZero, "1.234M"),
+            (RoundingStrategy::AwayFromZero, "1.235M"),
+        ];
+
+        fixtures.iter().for_each(|(strategy, expected)| {
+            let options = Options {
+                precision: Some(3),
+                rounding_strategy: Some(*strategy),
+                ..Default::default()
+            };
+
+            assert_eq!(
+                abbrev_num(1_234_500, Some(options)),
+                Some(expected.to_string())
+            );
+            assert_eq!(
+                abbrev_float(1_234_500.0, Some(options)),
+                Some(expected.to_string())
+            );
+        });
+
+        let result = abbrev_float(
+            1_005.0,
+            Some(Options {
+                precision: Some(2),
+                rounding_strategy: Some(RoundingStrategy::MidpointAwayFromZero),
+                ..Default::default()
+            }),
+        );
+        assert_eq!(result, Some("1.01k".to_string()));
+    }
+
+    #[test]
+    fn can_abbreviate_using_custom_units() {
+        let units = ["_c0", "_c1", "_c2", "_c3", "_c4", "_c5", "_c6", "_c7"];
+        let fixtures: Vec<(i64, &str)> = vec![
+            (0, "0_c0"),
+            (10, "10_c0"),
+            (1_000, "1_c1"),
+            (1_000_000, "1_c2"),
+            (1_000_000_000, "1_c3"),
+            (1_000_000_000_000, "1_c4"),
+            (1_000_000_000_000_000, "1_c5"),
+            (1_000_000_000_000_000_000, "1_c6"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev(
+                *case,
+                Some(Options {
+                    abbreviations: Some(&units),
+                    ..Default::default()
+                }),
+            );
+            assert_eq!(result, Some(expected.to_string()));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_using_fewer_custom_units() {
+        let options = Options {
+            abbreviations: Some(&["", "k"]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_num(999_499, Some(options)),
+            Some("999.5k".to_string())
+        );
+        assert_eq!(abbrev_num(999_999, Some(options)), None);
+        assert_eq!(abbrev_num(1_000_000, Some(options)), None);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn can_abbreviate_isize_bounds() {
+        assert_eq!(abbrev_num(isize::MIN, None), Some("-9.2E".to_string()));
+        assert_eq!(abbrev_num(isize::MAX, None), Some("9.2E".to_string()));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn can_abbreviate_isize_bounds() {
+        assert_eq!(abbrev_num(isize::MIN, None), Some("-2.1B".to_string()));
+        assert_eq!(abbrev_num(isize::MAX, None), Some("2.1B".to_string()));
+    }
+
+    #[test]
+    fn can_abbreviate_isize_like_fixed_width_integers() {
+        let fixtures: Vec<isize> = vec![isize::MIN, -1_500, 0, 999_999, isize::MAX];
+
+        fixtures.iter().for_each(|case| {
+            assert_eq!(abbrev_num(*case, None), abbrev(*case as i64, None));
+            assert_eq!(abbrev_num(*case, None), abbrev_i128(*case as i128, None));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_128_bit_integers() {
+        let fixtures: Vec<(i128, Option<&str>)> = vec![
+            (0, Some("0")),
+            (1_500, Some("1.5k")),
+            (9_223_372_036_854_775_807, Some("9.2E")),
+            (-9_223_372_036_854_775_809, Some("-9.2E")),
+            (999_000_000_000_000_000_000, Some("999E")),
+            (1_000_000_000_000_000_000_000, Some("1Z")),
+            (-2_500_000_000_000_000_000_000_000, Some("-2.5Y")),
+            (999_000_000_000_000_000_000_000_000, Some("999Y")),
+            (1_000_000_000_000_000_000_000_000_000, None),
+            (i128::MAX, None),
+            (i128::MIN, None),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_i128(*case, None);
+            assert_eq!(result, expected.map(|value| value.to_string()));
+        });
+
+        let units = ["", "k", "M", "B", "T", "P", "E", "Z", "Y"];
+        let result = abbrev_i128(
+            1_000_000_000_000_000_000_000,
+            Some(Options {
+                abbreviations: Some(&units),
+                ..Default::default()
+            }),
+        );
+        assert_eq!(result, Some("1Z".to_string()));
+
+        let units = ["", "", "", "", "", "", "_c6"];
+        let result = abbrev_i128(
+            123_456_789_012_345_678_901,
+            Some(Options {
+                abbreviations: Some(&units),
+                precision: Some(6),
+                ..Default::default()
+            }),
+        );
+        assert_eq!(result, Some("123.456789_c6".to_string()));
+    }
+
+    #[test]
+    fn can_abbreviate_floats() {
+        let fixtures: Vec<(f64, &str)> = vec![
+            (0.0, "0"),
+            (-0.0, "0"),
+            (0.5, "0.5"),
+            (12.34, "12.3"),
+            (999.0, "999"),
+            (1_500.0, "1.5k"),
+            (1_234.56, "1.2k"),
+            (-2_500_000.0, "-2.5M"),
+            (-0.04, "0"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_float(*case, None);
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(abbrev_float(f64::NAN, None), None);
+        assert_eq!(abbrev_float(0.0034, None), Some("0".to_string()));
+        assert_eq!(abbrev_float(f64::INFINITY, None), None);
+        assert_eq!(abbrev_float(f64::NEG_INFINITY, None), None);
+    }
+
+    #[test]
+    fn can_abbreviate_single_precision_floats() {
+        let fixtures: Vec<(f32, &str)> = vec![
+            (0.0, "0"),
+            (-0.0, "0"),
+            (0.5, "0.5"),
+            (12.34, "12.3"),
+            (1_500.0, "1.5k"),
+            (-2_500_000.0, "-2.5M"),
+            (1e24, "1Y"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_f32(*case, None);
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            precision: Some(1),
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_f32(2.45, Some(options)), Some("2.4".to_string()));
+        assert_eq!(
+            abbrev_float(2.45_f32 as f64, Some(options)),
+            Some("2.5".to_string())
+        );
+        assert_eq!(abbrev_f32(f32::NAN, None), None);
+        assert_eq!(abbrev_f32(f32::MAX, None), None);
+        assert_eq!(abbrev_f32(f32::INFINITY, None), None);
+        assert_eq!(abbrev_f32(f32::NEG_INFINITY, None), None);
+    }
+
+    #[test]
+    fn can_abbreviate_using_small_units() {
+        let options = Options {
+            small_units: Some(&SMALL_ABBREVIATIONS[..]),
+            ..Default::default()
+        };
+        let fixtures: Vec<(f64, Option<&str>)> = vec![
+            (0.0, Some("0")),
+            (0.5, Some("500m")),
+            (0.0034, Some("3.4m")),
+            (-0.0000125, Some("-12.5µ")),
+            (0.000_000_002, Some("2n")),
+            (0.000_000_000_001, Some("1p")),
+            (0.000_000_000_000_001, None),
+            (12.34, Some("12.3")),
+            (1_500.0, Some("1.5k")),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_float(*case, Some(options));
+            assert_eq!(result, expected.map(|value| value.to_string()));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_using_custom_base() {
+        let options = Options {
+            base: Some(1024),
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_num(1_023, Some(options)), Some("1023".to_string()));
+        assert_eq!(abbrev_num(1_536, Some(options)), Some("1.5k".to_string()));
+        assert_eq!(
+            abbrev_float(-1_536.0, Some(options)),
+            Some("-1.5k".to_string())
+        );
+
+        let options = Options {
+            base: Some(1),
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_num(1_000, Some(options)), None);
+    }
+
+    #[test]
+    fn can_abbreviate_bytes() {
+        let fixtures: Vec<(u64, &str)> = vec![
+            (0, "0B"),
+            (512, "512B"),
+            (1_024, "1KiB"),
+            (1_536, "1.5KiB"),
+            (1_048_576, "1MiB"),
+            (5_368_709_120, "5GiB"),
+            (u64::MAX, "16EiB"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_bytes(*case, None);
+            assert_eq!(result, Some(expected.to_string()));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_bytes_using_si_units() {
+        let fixtures: Vec<(u64, &str)> = vec![
+            (0, "0B"),
+            (512, "512B"),
+            (1_000, "1kB"),
+            (1_500_000, "1.5MB"),
+            (2_000_000_000_000, "2TB"),
+            (u64::MAX, "18.4EB"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_bytes_si(*case, None);
+            assert_eq!(result, Some(expected.to_string()));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_bits() {
+        let fixtures: Vec<(u64, &str)> = vec![
+            (0, "0bps"),
+            (512, "512bps"),
+            (1_000, "1kbps"),
+            (1_500_000, "1.5Mbps"),
+            (2_000_000_000_000, "2Tbps"),
+            (u64::MAX, "18.4Ebps"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_bits(*case, None);
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            long_bit_rate: true,
+            space: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_bits(100_000_000, Some(options)),
+            Some("100 Mbit/s".to_string())
+        );
+        assert_eq!(abbrev_bits(64, Some(options)), Some("64 bit/s".to_string()));
+    }
+
+    #[test]
+    fn can_abbreviate_with_space() {
+        let options = Options {
+            space: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (0, "0"),
+            (150, "150"),
+            (-150, "-150"),
+            (1_500, "1.5 k"),
+            (-1_500, "-1.5 k"),
+            (2_000_000, "2 M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(abbrev_float(0.5, Some(options)), Some("0.5".to_string()));
+        assert_eq!(abbrev_bytes(0, Some(options)), Some("0 B".to_string()));
+        assert_eq!(abbrev_bytes(512, Some(options)), Some("512 B".to_string()));
+        assert_eq!(
+            abbrev_bytes(1_536, Some(options)),
+            Some("1.5 KiB".to_string())
+        );
+        assert_eq!(abbrev_bytes_si(0, Some(options)), Some("0 B".to_string()));
+    }
+
+    #[test]
+    fn can_abbreviate_using_long_words() {
+        let options = Options {
+            long_words: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, &str)> = vec![
+            (150, "150"),
+            (2_000, "2 thousand"),
+            (1_500_000, "1.5 million"),
+            (-3_000_000_000, "-3 billion"),
+            (1_000_000_000_000_000_000, "1 quintillion"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(
+            abbrev_i128(2_000_000_000_000_000_000_000_000, Some(options)),
+            Some("2 septillion".to_string())
+        );
+
+        assert_eq!(
+            parse_abbrev("1.5 million", Some(options)),
+            Some(1_500_000.0)
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_using_custom_decimal_separator() {
+        let options = Options {
+            precision: Some(3),
+            decimal_separator: Some(','),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_num(1_566_450, Some(options)),
+            Some("1,566M".to_string())
+        );
+        assert_eq!(abbrev_num(2_000_000, Some(options)), Some("2M".to_string()));
+        assert_eq!(abbrev_num(999, Some(options)), Some("999".to_string()));
+        assert_eq!(
+            abbrev_float(-12.25, Some(options)),
+            Some("-12,25".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_using_group_separator() {
+        let options = Options {
+            group_separator: Some(','),
+            base: Some(1_000_000),
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, &str)> = vec![
+            (0, "0"),
+            (999, "999"),
+            (1_000, "1,000"),
+            (-12_345, "-12,345"),
+            (999_999, "999,999"),
+            (12_345_678_900, "12,345.7k"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            group_separator: Some('.'),
+            decimal_separator: Some(','),
+            base: Some(1_000_000),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_float(1_234.5, Some(options)),
+            Some("1.234,5".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_using_grouped_fraction() {
+        let options = Options {
+            precision: Some(6),
+            group_separator: Some(' '),
+            group_fraction: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(Options, i64, &str)> = vec![
+            (options, 1_234_567, "1.234 567M"),
+            (options, 1_234_000, "1.234M"),
+            (options, 1_234_567_890, "1.234 568B"),
+            (options, -1_234_500, "-1.234 5M"),
+            (
+                Options {
+                    fixed_unit: Some("k"),
+                    ..options
+                },
+                1_234_567_890,
+                "1 234 567.89k",
+            ),
+            (
+                Options {
+                    precision: Some(7),
+                    keep_trailing_zeros: true,
+                    ..options
+                },
+                1_234_567,
+                "1.234 567 0M",
+            ),
+            (
+                Options {
+                    group_separator: None,
+                    ..options
+                },
+                1_234_567,
+                "1.234567M",
+            ),
+            (
+                Options {
+                    group_fraction: false,
+                    ..options
+                },
+                1_234_567,
+                "1.234567M",
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, case, expected)| {
+            let result = abbrev(*case, Some(*options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_using_indian_grouping() {
+        let options = Options {
+            group_separator: Some(','),
+            grouping: Grouping::Indian,
+            notation: NotationMode::Standard,
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, &str)> = vec![
+            (999, "999"),
+            (1_000, "1,000"),
+            (12_345, "12,345"),
+            (123_456, "1,23,456"),
+            (-12_345_678, "-1,23,45,678"),
+            (1_234_567_890, "1,23,45,67,890"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_using_locale_presets() {
+        let fixtures: Vec<(Locale, i64, &str)> = vec![
+            (Locale::EnUs, 1_234_567, "1.2M"),
+            (Locale::DeDe, 1_234_567, "1,2\u{00A0}M"),
+            (Locale::DeDe, 999, "999"),
+            (Locale::FrFr, -1_500, "-1,5\u{00A0}k"),
+            (Locale::EnIn, 150_000, "1.5L"),
+            (Locale::EnIn, 25_000_000, "2.5Cr"),
+            (Locale::EnIn, 1_234_567_800_000, "1,23,456.8Cr"),
+        ];
+
+        fixtures.iter().for_each(|(locale, case, expected)| {
+            let result = abbrev(*case, Some(Options::for_locale(*locale)));
+            assert_eq!(result, Some(expected.to_string()), "{locale:?}");
+        });
+
+        let options = Options {
+            precision: Some(2),
+            unit_separator: None,
+            ..Options::for_locale(Locale::DeDe)
+        };
+
+        assert_eq!(
+            abbrev_num(1_234_567, Some(options)),
+            Some("1,23M".to_string())
+        );
+
+        let fixtures: Vec<(Locale, &str)> = vec![
+            (Locale::EnUs, "1,234.6k"),
+            (Locale::DeDe, "1.234,6\u{00A0}k"),
+            (Locale::FrFr, "1\u{202F}234,6\u{00A0}k"),
+        ];
+
+        fixtures.iter().for_each(|(locale, expected)| {
+            let options = Options {
+                fixed_unit: Some("k"),
+                ..Default::default()
+            }
+            .with_locale(*locale);
+            let result = abbrev_num(1_234_567, Some(options));
+            assert_eq!(result, Some(expected.to_string()), "{locale:?}");
+        });
+    }
+
+    #[test]
+    fn can_find_locales_by_tag() {
+        let fixtures: Vec<(&str, Option<Locale>)> = vec![
+            ("en-US", Some(Locale::EnUs)),
+            ("de-DE", Some(Locale::DeDe)),
+            ("FR_fr", Some(Locale::FrFr)),
+            ("en-in", Some(Locale::EnIn)),
+            ("en", None),
+            ("en-GB", None),
+            ("", None),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            assert_eq!(Locale::from_tag(case), *expected);
+        });
+
+        assert_eq!(Locale::DeDe.tag(), "de-DE");
+    }
+
+    #[test]
+    fn can_abbreviate_with_minimum_precision() {
+        let options = Options {
+            precision: Some(2),
+            min_precision: Some(2),
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (150, "150.00"),
+            (1_000_000, "1.00M"),
+            (1_200_000, "1.20M"),
+            (1_234_000, "1.23M"),
+            (-1_000, "-1.00k"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            precision: Some(3),
+            min_precision: Some(1),
+            decimal_separator: Some(','),
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_num(1_000, Some(options)), Some("1,0k".to_string()));
+        assert_eq!(abbrev_num(1_234, Some(options)), Some("1,234k".to_string()));
+    }
+
+    #[test]
+    fn can_abbreviate_with_decimal_point() {
+        let options = Options {
+            always_decimal: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (0, "0"),
+            (999, "999"),
+            (1_000, "1.0k"),
+            (1_000_000, "1.0M"),
+            (1_234_000, "1.2M"),
+            (-2_000_000, "-2.0M"),
+            (999_999, "1.0M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let fixtures: Vec<(Options, &str)> = vec![
+            (
+                Options {
+                    precision: Some(0),
+                    ..options
+                },
+                "2.0M",
+            ),
+            (
+                Options {
+                    precision: Some(3),
+                    ..options
+                },
+                "1.5M",
+            ),
+            (
+                Options {
+                    min_precision: Some(2),
+                    ..options
+                },
+                "1.50M",
+            ),
+            (
+                Options {
+                    notation: NotationMode::Scientific,
+                    ..options
+                },
+                "1.5e6",
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, expected)| {
+            let result = abbrev_num(1_500_000, Some(*options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+    }
+
+    #[test]
+    fn can_skip_abbreviating_below_threshold() {
+        let options = Options {
+            min_abbrev_value: Some(10_000),
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (999, "999"),
+            (5_000, "5000"),
+            (-9_999, "-9999"),
+            (10_000, "10k"),
+            (15_000, "15k"),
+            (2_000_000, "2M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            group_separator: Some(','),
+            ..options
+        };
+
+        assert_eq!(abbrev_num(5_000, Some(options)), Some("5,000".to_string()));
+        assert_eq!(
+            abbrev_float(9_999.25, Some(options)),
+            Some("9,999.2".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_using_custom_step() {
+        let options = Options {
+            step: Some(6),
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, &str)> = vec![
+            (999_999, "999999"),
+            (1_000_000, "1k"),
+            (2_500_000_000, "2500k"),
+            (-1_500_000_000_000, "-1.5M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            step: Some(3),
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_num(1_500, Some(options)), abbrev_num(1_500, None));
+        assert_eq!(abbrev_float(1.5e6, Some(options)), Some("1.5M".to_string()));
+
+        let options = Options {
+            step: Some(0),
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_num(1_500, Some(options)), None);
+    }
+
+    #[test]
+    fn can_describe_abbreviation_errors() {
+        let options = Options {
+            abbreviations: Some(&["", "k"]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            try_abbrev_num(-1_500, Some(options)),
+            Ok("-1.5k".to_string())
+        );
+        assert_eq!(
+            try_abbrev_num(1_000_000, Some(options)),
+            Err(AbbrevError::MagnitudeOutOfRange { level: 2 })
+        );
+        assert_eq!(
+            try_abbrev_num(
+                1_000,
+                Some(Options {
+                    base: Some(1),
+                    ..Default::default()
+                })
+            ),
+            Err(AbbrevError::InvalidBase)
+        );
+        assert_eq!(
+            float_parts(f64::NAN, &Options::default()),
+            Err(AbbrevError::NotFinite)
+        );
+        assert_eq!(
+            float_parts(
+                1e-15,
+                &Options {
+                    small_units: Some(&SMALL_ABBREVIATIONS[..]),
+                    ..Default::default()
+                }
+            ),
+            Err(AbbrevError::MagnitudeOutOfRange { level: -5 })
+        );
+        assert_eq!(
+            float_parts(1e30, &Options::default()),
+            Err(AbbrevError::ConversionFailed)
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_any_integer_type() {
+        assert_eq!(abbrev(0_u8, None), Some("0".to_string()));
+        assert_eq!(abbrev(-128_i8, None), Some("-128".to_string()));
+        assert_eq!(abbrev(1_500_u16, None), Some("1.5k".to_string()));
+        assert_eq!(abbrev(-32_768_i16, None), Some("-32.8k".to_string()));
+        assert_eq!(abbrev(4_000_000_000_u32, None), Some("4B".to_string()));
+        assert_eq!(abbrev(-2_000_i64, None), Some("-2k".to_string()));
+        assert_eq!(abbrev(u64::MAX, None), Some("18.4E".to_string()));
+        assert_eq!(abbrev(1_500_usize, None), Some("1.5k".to_string()));
+        assert_eq!(abbrev(-1_500_isize, None), abbrev_num(-1_500, None));
+
+        let units = [
+            "", "k", "M", "B", "T", "P", "E", "Z", "Y", "R", "Q", "_c11", "_c12",
+        ];
+        let options = Options {
+            abbreviations: Some(&units),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev(i128::MIN, Some(options)),
+            Some("-170.1_c12".to_string())
+        );
+        assert_eq!(
+            abbrev(u128::MAX, Some(options)),
+            Some("340.3_c12".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_with_prefix_and_suffix() {
+        let options = Options {
+            prefix: Some("$"),
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (0, "$0"),
+            (150, "$150"),
+            (1_500_000, "$1.5M"),
+            (-5_000, "-$5k"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            prefix: Some("$"),
+            suffix: Some(" USD"),
+            group_separator: Some(','),
+            min_abbrev_value: Some(1_000_000),
+            force_sign: true,
+            min_precision: Some(1),
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, &str)> = vec![
+            (150, "+$150.0 USD"),
+            (-150_000, "-$150,000.0 USD"),
+            (1_500_000, "+$1.5M USD"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let expected = Some(expected.to_string());
+            assert_eq!(abbrev(*case, Some(options)), expected);
+            assert_eq!(abbrev_i128(*case as i128, Some(options)), expected);
+            assert_eq!(abbrev_float(*case as f64, Some(options)), expected);
+            assert_eq!(
+                abbrev_decimal(Decimal::from(*case), Some(options)),
+                expected
+            );
+        });
+
+        let options = Options {
+            prefix: Some("$"),
+            sign_position: SignPosition::AfterPrefix,
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_num(-5_000, Some(options)), Some("$-5k".to_string()));
+
+        let options = Options {
+            sign_position: SignPosition::Suffix,
+            suffix: Some(" USD"),
+            ..options
+        };
+        let fixtures: Vec<(Options, isize, &str)> = vec![
+            (options, -5_000, "$5k- USD"),
+            (options, 1_500, "$1.5k USD"),
+            (
+                Options {
+                    force_sign: true,
+                    ..options
+                },
+                1_500,
+                "$1.5k+ USD",
+            ),
+            (
+                Options {
+                    force_sign: true,
+                    ..options
+                },
+                0,
+                "$0 USD",
+            ),
+            (
+                Options {
+                    negative_style: NegativeStyle::Parentheses,
+                    ..options
+                },
+                -5_000,
+                "($5k USD)",
+            ),
+            (
+                Options {
+                    width: Some(10),
+                    prefix: None,
+                    suffix: None,
+                    space: true,
+                    ..options
+                },
+                -150,
+                "      150-",
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, case, expected)| {
+            let result = abbrev_num(*case, Some(*options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            suffix: Some("€"),
+            space: true,
+            decimal_separator: Some(','),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_num(1_500_000, Some(options)),
+            Some("1,5 M€".to_string())
+        );
+        assert_eq!(
+            abbrev_bytes(1_536, Some(options)),
+            Some("1,5 KiB€".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_percentages() {
+        let fixtures: Vec<(f64, &str)> = vec![
+            (0.0, "0%"),
+            (0.1234, "12.3%"),
+            (-0.005, "-0.5%"),
+            (0.0001, "0%"),
+            (1.0, "100%"),
+            (2.5, "250%"),
+            (15.0, "1.5k%"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_percent(*case, None);
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            min_abbrev_value: Some(usize::MAX),
+            group_separator: Some(','),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_percent(15.0, Some(options)),
+            Some("1,500%".to_string())
+        );
+        assert_eq!(abbrev_percent(f64::NAN, None), None);
+    }
+
+    #[test]
+    fn can_promote_rounded_mantissa_to_next_unit() {
+        let fixtures: Vec<(isize, u32, &str)> = vec![
+            (999_500, 0, "1M"),
+            (999_500, 1, "999.5k"),
+            (999_950, 0, "1M"),
+            (999_950, 1, "1M"),
+            (999_950, 2, "999.95k"),
+            (999_999, 0, "1M"),
+            (999_999, 1, "1M"),
+            (999_999, 2, "1M"),
+            (999_999_999, 0, "1B"),
+            (999_999_999, 1, "1B"),
+            (999_999_999, 2, "1B"),
+            (9_999_999, 0, "10M"),
+            (9_999_999, 1, "10M"),
+            (999_949_999, 1, "999.9M"),
+            (999_950_000, 1, "1B"),
+        ];
+
+        fixtures.iter().for_each(|(case, precision, expected)| {
+            let options = Options {
+                precision: Some(*precision),
+                ..Default::default()
+            };
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()), "{case} at {precision}");
+        });
+
+        assert_eq!(abbrev_num(-999_999, None), Some("-1M".to_string()));
+        assert_eq!(abbrev_float(999.96, None), Some("1k".to_string()));
+        assert_eq!(abbrev_float(999_999.9, None), Some("1M".to_string()));
+
+        let options = Options {
+            precision: Some(2),
+            keep_trailing_zeros: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_num(999_999, Some(options)),
+            Some("1.00M".to_string())
+        );
+
+        let options = Options {
+            min_abbrev_value: Some(10_000),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_float(9_999.9, Some(options)),
+            Some("9999.9".to_string())
+        );
+        assert_eq!(
+            abbrev_float(9_999.96, Some(options)),
+            Some("10k".to_string())
+        );
+
+        let options = Options {
+            small_units: Some(&SMALL_ABBREVIATIONS[..]),
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_float(0.99996, Some(options)), Some("1".to_string()));
+        assert_eq!(
+            abbrev_float(0.00099996, Some(options)),
+            Some("1m".to_string())
+        );
+    }
+
+    #[test]
+    fn cannot_promote_past_last_unit() {
+        let options = Options {
+            precision: Some(0),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_i128(999_999_999_999_999_999_999, Some(options)),
+            Some("1Z".to_string())
+        );
+        assert_eq!(
+            abbrev_i128(999_999_999_999_999_999_999_999_999, Some(options)),
+            None
+        );
+        assert_eq!(
+            try_abbrev_num(
+                999_999,
+                Some(Options {
+                    abbreviations: Some(&["", "k"]),
+                    ..Default::default()
+                })
+            ),
+            Err(AbbrevError::MagnitudeOutOfRange { level: 2 })
+        );
+    }
+
+    #[test]
+    fn can_write_into_sink() {
+        let mut out = String::new();
+
+        [0, 999, 1_500, -2_300_000].iter().for_each(|case| {
+            out.clear();
+            assert_eq!(write_abbrev(&mut out, *case, None), Ok(()));
+            assert_eq!(Some(out.clone()), abbrev_num(*case, None));
+        });
+
+        let options = Options {
+            abbreviations: Some(&["", "k"]),
+            ..Default::default()
+        };
+
+        out.clear();
+        assert_eq!(
+            write_abbrev(&mut out, 1_500_000, Some(options)),
+            Err(AbbrevError::MagnitudeOutOfRange { level: 2 })
+        );
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn can_report_failing_sink() {
+        struct Failing;
+
+        impl fmt::Write for Failing {
+            fn write_str(&mut self, _: &str) -> fmt::Result {
+                Err(fmt::Error)
+            }
+        }
+
+        assert_eq!(
+            write_abbrev(&mut Failing, 1_500, None),
+            Err(AbbrevError::WriteFailed)
+        );
+    }
+
+    #[test]
+    fn can_fall_back_to_scientific_notation() {
+        let options = Options {
+            scientific_fallback: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(i128, &str)> = vec![
+            (1_500_000, "1.5M"),
+            (10_000_000_000_000_000_000_000, "10Z"),
+            (999_999_999_999_999_999_999_999_999, "1e27"),
+            (10_000_000_000_000_000_000_000_000_000, "1e28"),
+            (-12_345_000_000_000_000_000_000_000_000, "-1.2e28"),
+            (99_960_000_000_000_000_000_000_000_000, "1e29"),
+            (i128::MAX, "1.7e38"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_i128(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            precision: Some(3),
+            abbreviations: Some(&["", "k"]),
+            scientific_fallback: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_num(1_234_567, Some(options)),
+            Some("1.235e6".to_string())
+        );
+        assert_eq!(
+            abbrev_float(-1.5e25, Some(options)),
+            Some("-1.5e25".to_string())
+        );
+        assert_eq!(
+            abbrev_num(12_345, Some(options)),
+            Some("12.345k".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_decimals() {
+        let options = Options {
+            precision: Some(2),
+            ..Default::default()
+        };
+        let fixtures: Vec<(&str, &str)> = vec![
+            ("0", "0"),
+            ("0.004", "0"),
+            ("12.345", "12.34"),
+            ("12.355", "12.36"),
+            ("1234567.89", "1.23M"),
+            ("-999999.999", "-1M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let number: Decimal = case.parse().unwrap();
+            let result = abbrev_decimal(number, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(abbrev_decimal(Decimal::MAX, Some(options)), None);
+    }
+
+    #[test]
+    fn can_pad_to_width() {
+        let options = Options {
+            width: Some(6),
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (0, "     0"),
+            (999, "   999"),
+            (1_500, "  1.5k"),
+            (-1_500, " -1.5k"),
+            (-123_456_789, "-123.5M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            width: Some(8),
+            fill: Some('·'),
+            align: Align::Left,
+            prefix: Some("€"),
+            space: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_num(-1_500, Some(options)),
+            Some("-€1.5 k·".to_string())
+        );
+        assert_eq!(
+            abbrev_num(-1_234_567_890, Some(options)),
+            Some("-€1.2 B·".to_string())
+        );
+        assert_eq!(
+            abbrev_float(0.5, Some(options)),
+            Some("€0.5····".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_using_notation_modes() {
+        let fixtures: Vec<(NotationMode, isize, &str)> = vec![
+            (NotationMode::Compact, 1_500_000, "1.5M"),
+            (NotationMode::Standard, 1_500_000, "1500000"),
+            (NotationMode::Standard, -999, "-999"),
+            (NotationMode::Scientific, 1_500_000, "1.5e6"),
+            (NotationMode::Scientific, -15_000, "-1.5e4"),
+            (NotationMode::Scientific, 7, "7e0"),
+            (NotationMode::Scientific, 99_960, "1e5"),
+            (NotationMode::Engineering, 1_500_000, "1.5e6"),
+            (NotationMode::Engineering, 15_000, "15e3"),
+            (NotationMode::Engineering, 999_960, "1e6"),
+            (NotationMode::Engineering, 0, "0"),
+        ];
+
+        fixtures.iter().for_each(|(notation, case, expected)| {
+            let options = Options {
+                notation: *notation,
+                ..Default::default()
+            };
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            notation: NotationMode::Standard,
+            group_separator: Some(','),
+            precision: Some(2),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_float(1_234_567.891, Some(options)),
+            Some("1,234,567.89".to_string())
+        );
+        assert_eq!(
+            abbrev_i128(i128::MIN, Some(options)).map(|value| value.len()),
+            Some(52)
+        );
+
+        let options = Options {
+            notation: NotationMode::Engineering,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_float(0.0015, Some(options)),
+            Some("1.5e-3".to_string())
+        );
+        assert_eq!(
+            abbrev_float(0.15, Some(options)),
+            Some("150e-3".to_string())
+        );
+        assert_eq!(
+            abbrev_i128(i128::MAX, Some(options)),
+            Some("170.1e36".to_string())
+        );
+
+        let options = Options {
+            notation: NotationMode::Scientific,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_float(0.0015, Some(options)),
+            Some("1.5e-3".to_string())
+        );
+        assert_eq!(
+            abbrev_i128(i128::MAX, Some(options)),
+            Some("1.7e38".to_string())
+        );
+    }
+
+    #[test]
+    fn can_round_negative_numbers_differently() {
+        let options = Options {
+            precision: Some(0),
+            rounding_strategy: Some(RoundingStrategy::MidpointAwayFromZero),
+            negative_rounding_strategy: Some(RoundingStrategy::MidpointTowardZero),
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (1_500, "2k"),
+            (-1_500, "-1k"),
+            (2_500_000, "3M"),
+            (-2_500_000, "-2M"),
+            (-2_600_000, "-3M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(abbrev_float(-1.5, Some(options)), Some("-1".to_string()));
+        assert_eq!(abbrev_i128(-i128::MAX, Some(options)), None);
+
+        let options = Options {
+            scientific_fallback: true,
+            precision: Some(0),
+            negative_rounding_strategy: Some(RoundingStrategy::ToZero),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_i128(-i128::MAX, Some(options)),
+            Some("-1e38".to_string())
+        );
+        assert_eq!(
+            abbrev_i128(i128::MAX, Some(options)),
+            Some("2e38".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_using_unit_separator() {
+        let options = Options {
+            unit_separator: Some("\u{00A0}"),
+            space: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (0, "0"),
+            (999, "999"),
+            (1_500_000, "1.5\u{00A0}M"),
+            (-2_000, "-2\u{00A0}k"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            unit_separator: Some("\u{2009}"),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_bytes(1_536, Some(options)),
+            Some("1.5\u{2009}KiB".to_string())
+        );
+        assert_eq!(
+            abbrev_bytes(512, Some(options)),
+            Some("512\u{2009}B".to_string())
+        );
+    }
+
+    #[test]
+    fn can_parenthesize_negative_numbers() {
+        let options = Options {
+            negative_style: NegativeStyle::Parentheses,
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (0, "0"),
+            (1_500, "1.5k"),
+            (-1_500, "(1.5k)"),
+            (-999, "(999)"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(abbrev_float(-0.01, Some(options)), Some("0".to_string()));
+
+        let options = Options {
+            negative_style: NegativeStyle::Parentheses,
+            sign_position: SignPosition::AfterPrefix,
+            prefix: Some("$"),
+            suffix: Some(" USD"),
+            width: Some(12),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_num(-1_500, Some(options)),
+            Some(" ($1.5k USD)".to_string())
+        );
+        assert_eq!(
+            abbrev_num(1_500, Some(options)),
+            Some("   $1.5k USD".to_string())
+        );
+    }
+
+    #[test]
+    fn can_write_superscript_exponents() {
+        let options = Options {
+            notation: NotationMode::Scientific,
+            superscript_exponent: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_num(1_500, Some(options)),
+            Some("1.5×10³".to_string())
+        );
+        assert_eq!(abbrev_num(-7, Some(options)), Some("-7×10⁰".to_string()));
+        assert_eq!(
+            abbrev_float(0.0015, Some(options)),
+            Some("1.5×10⁻³".to_string())
+        );
+        assert_eq!(
+            abbrev_i128(12_000_000_000_000_000_000_000_000_000, Some(options)),
+            Some("1.2×10²⁸".to_string())
+        );
+
+        let options = Options {
+            notation: NotationMode::Engineering,
+            superscript_exponent: true,
+            precision: Some(2),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_num(45_678_901, Some(options)),
+            Some("45.68×10⁶".to_string())
+        );
+        assert_eq!(abbrev_num(0, Some(options)), Some("0".to_string()));
+    }
+
+    #[test]
+    fn can_round_to_significant_digits() {
+        let options = Options {
+            significant_digits: Some(3),
+            precision: Some(0),
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (0, "0"),
+            (5, "5"),
+            (1_234, "1.23k"),
+            (12_345, "12.3k"),
+            (123_456, "123k"),
+            (-987_654_321, "-988M"),
+            (999_999, "1M"),
+            (99_950, "100k"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            significant_digits: Some(2),
+            min_abbrev_value: Some(1_000_000),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_float(0.001234, Some(options)),
+            Some("0.0012".to_string())
+        );
+        assert_eq!(
+            abbrev_num(123_456, Some(options)),
+            Some("120000".to_string())
+        );
+        assert_eq!(
+            abbrev_num(1_234_567, Some(options)),
+            Some("1.2M".to_string())
+        );
+    }
+
+    #[test]
+    fn can_keep_trailing_zeros() {
+        let options = Options {
+            precision: Some(2),
+            keep_trailing_zeros: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (0, "0.00"),
+            (999, "999.00"),
+            (1_000_000, "1.00M"),
+            (1_200_000, "1.20M"),
+            (-1_234_567, "-1.23M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            keep_trailing_zeros: true,
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_num(2_000, Some(options)), Some("2.0k".to_string()));
+    }
+
+    #[test]
+    fn can_abbreviate_with_fixed_normalization() {
+        let options = Options {
+            precision: Some(2),
+            normalization: Normalization::Fixed,
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (0, "0.00"),
+            (999, "999.00"),
+            (1_000_000, "1.00M"),
+            (1_500_000, "1.50M"),
+            (-1_234_567, "-1.23M"),
+            (999_999, "1.00M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(
+            abbrev_decimal(Decimal::new(1_000_000_000, 3), Some(options)),
+            Some("1.00M".to_string())
+        );
+        assert_eq!(
+            abbrev_num(
+                1_000_000,
+                Some(Options {
+                    normalization: Normalization::Trim,
+                    ..options
+                })
+            ),
+            Some("1M".to_string())
+        );
+    }
+
+    #[test]
+    fn can_format_zero_like_other_numbers() {
+        let options = Options {
+            min_precision: Some(2),
+            prefix: Some("$"),
+            suffix: Some(" USD"),
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_num(0, Some(options)), Some("$0.00 USD".to_string()));
+        assert_eq!(
+            abbrev_float(-0.001, Some(options)),
+            Some("$0.00 USD".to_string())
+        );
+        assert_eq!(abbrev_bytes(0, None), Some("0B".to_string()));
+        assert_eq!(abbrev_bytes_si(0, None), Some("0B".to_string()));
+        assert_eq!(abbrev_percent(0.0, None), Some("0%".to_string()));
+
+        let options = Options {
+            zero_text: Some("—"),
+            prefix: Some("$"),
+            width: Some(3),
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_num(0, Some(options)), Some("  —".to_string()));
+        assert_eq!(abbrev_num(5, Some(options)), Some(" $5".to_string()));
+    }
+
+    #[test]
+    fn can_pluralize_units() {
+        let options = Options {
+            abbreviations: Some(&["", "grand", "mega"]),
+            long_words_plural: Some(&["", "grands"]),
+            long_words: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (0, "0"),
+            (1, "1"),
+            (1_000, "1 grand"),
+            (-1_000, "-1 grand"),
+            (2_000, "2 grands"),
+            (1_500, "1.5 grands"),
+            (1_040, "1 grand"),
+            (1_000_000, "1 mega"),
+            (3_000_000, "3 mega"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            abbreviations: Some(&[" unit", " thousand"]),
+            long_words_plural: Some(&[" units", " thousand"]),
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_num(1, Some(options)), Some("1 unit".to_string()));
+        assert_eq!(abbrev_num(0, Some(options)), Some("0 units".to_string()));
+        assert_eq!(
+            abbrev_float(2.5, Some(options)),
+            Some("2.5 units".to_string())
+        );
+        assert_eq!(
+            abbrev_num(5_000, Some(options)),
+            Some("5 thousand".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_using_unit_scale() {
+        let options = Options {
+            unit_scale: Some(&[(1_000, "K"), (100_000, "L"), (10_000_000, "Cr")]),
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, &str)> = vec![
+            (0, "0"),
+            (999, "999"),
+            (1_000, "1K"),
+            (99_999, "1L"),
+            (99_999_999, "10Cr"),
+            (99_960, "1L"),
+            (1_500_000, "15L"),
+            (-9_999_999, "-1Cr"),
+            (150_000_000, "15Cr"),
+            (1_000_000_000_000, "100000Cr"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(
+            abbrev_float(12.345, Some(options)),
+            Some("12.3".to_string())
+        );
+        assert_eq!(
+            abbrev_i128(10_i128.pow(33), Some(options)),
+            Some("100000000000000000000000000Cr".to_string())
+        );
+        assert_eq!(abbrev_i128(i128::MAX, Some(options)), None);
+    }
+
+    #[test]
+    fn can_abbreviate_using_mixed_base_unit_scale() {
+        let options = Options {
+            unit_scale: Some(&[(60, "m"), (3_600, "h"), (86_400, "d"), (604_800, "w")]),
+            space: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (59, "59"),
+            (60, "1 m"),
+            (90, "1.5 m"),
+            (3_599, "1 h"),
+            (5_400, "1.5 h"),
+            (86_399, "1 d"),
+            (129_600, "1.5 d"),
+            (604_799, "1 w"),
+            (-1_814_400, "-3 w"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            unit_scale: Some(&[(1_024, "Ki"), (1_000_000, "M"), (1 << 30, "Gi")]),
+            base: Some(10),
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, &str)> = vec![
+            (1_536, "1.5Ki"),
+            (500_000, "488.3Ki"),
+            (2_500_000, "2.5M"),
+            (1_073_741_824, "1Gi"),
+            (3_221_225_472, "3Gi"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_using_myriad_units() {
+        let options = Options {
+            abbreviations: Some(&MYRIAD_ABBREVIATIONS[..]),
+            step: Some(4),
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, &str)> = vec![
+            (9_999, "9999"),
+            (10_000, "1万"),
+            (12_340_000, "1234万"),
+            (99_999_999, "1億"),
+            (150_000_000, "1.5億"),
+            (-2_500_000_000_000, "-2.5兆"),
+            (3_000_000_000_000_000, "3000兆"),
+            (30_000_000_000_000_000, "3京"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(parse_abbrev("1.5億", Some(options)), Some(150_000_000.0));
+        assert_eq!(
+            abbrev_i128(10_i128.pow(20), Some(options)),
+            Some("1垓".to_string())
+        );
+        assert_eq!(abbrev_i128(10_i128.pow(24), Some(options)), None);
+    }
+
+    #[test]
+    fn can_abbreviate_using_long_scale() {
+        let options = Options {
+            long_words: true,
+            scale: NumberScale::Long,
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, &str)> = vec![
+            (1_500, "1.5 thousand"),
+            (2_000_000, "2 million"),
+            (1_000_000_000, "1 milliard"),
+            (1_000_000_000_000, "1 billion"),
+            (-3_000_000_000_000_000, "-3 billiard"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(parse_abbrev("1 milliard", Some(options)), Some(1e9));
+        assert_eq!(
+            abbrev_num(
+                1_000_000_000,
+                Some(Options {
+                    long_words: false,
+                    ..options
+                })
+            ),
+            Some("1B".to_string())
+        );
+        assert_eq!(
+            abbrev_num(
+                1_000_000_000,
+                Some(Options {
+                    scale: NumberScale::Short,
+                    ..options
+                })
+            ),
+            Some("1 billion".to_string())
+        );
+    }
+
+    #[test]
+    fn can_return_unit_level() {
+        let fixtures: Vec<(isize, Option<(u32, &str)>)> = vec![
+            (0, Some((0, ""))),
+            (999, Some((0, ""))),
+            (1_000, Some((1, "k"))),
+            (-1_500_000, Some((2, "M"))),
+            (999_949, Some((1, "k"))),
+            (999_950, Some((2, "M"))),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            assert_eq!(unit_level(*case, None), *expected);
+        });
+
+        let options = Options {
+            abbreviations: Some(&["", "k"]),
+            ..Default::default()
+        };
+
+        assert_eq!(unit_level(1_500, Some(options)), Some((1, "k")));
+        assert_eq!(unit_level(999_999, Some(options)), None);
+        assert_eq!(unit_level(1_500_000, Some(options)), None);
+    }
+
+    #[test]
+    fn can_abbreviate_using_fixed_unit() {
+        let options = Options {
+            fixed_unit: Some("M"),
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (0, "0M"),
+            (40_000, "0M"),
+            (500_000, "0.5M"),
+            (-2_000_000, "-2M"),
+            (999_999, "1M"),
+            (1_234_000_000, "1234M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(
+            abbrev_float(1.5e9, Some(options)),
+            Some("1500M".to_string())
+        );
+        assert_eq!(
+            abbrev_i128(10_i128.pow(30), Some(options)),
+            Some("1000000000000000000000000M".to_string())
+        );
+        assert_eq!(abbrev_i128(i128::MAX, Some(options)), None);
+        assert_eq!(
+            try_abbrev_num(
+                1_500,
+                Some(Options {
+                    fixed_unit: Some("X"),
+                    ..options
+                })
+            ),
+            Err(AbbrevError::UnknownUnit)
+        );
+    }
+
+    #[test]
+    fn can_fit_to_max_width() {
+        let options = Options {
+            max_width: Some(5),
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (0, "0"),
+            (150, "150"),
+            (12_345, "12k"),
+            (1_234_567, "1.2M"),
+            (-1_234_567, "-1.2M"),
+            (123_456_789, "123M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            max_width: Some(6),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_num(1_234_567, Some(options)),
+            Some("1.23M".to_string())
+        );
+        assert_eq!(
+            abbrev_float(0.123456, Some(options)),
+            Some("0.123".to_string())
+        );
+        assert_eq!(
+            abbrev_num(
+                1_234_567,
+                Some(Options {
+                    precision: Some(1),
+                    ..options
+                })
+            ),
+            Some("1.2M".to_string())
+        );
+        assert_eq!(
+            abbrev_num(
+                1_000_000,
+                Some(Options {
+                    keep_trailing_zeros: true,
+                    ..options
+                })
+            ),
+            Some("1.00M".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_rounding_up_and_down() {
+        let options = Options {
+            precision: Some(0),
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str, &str)> = vec![
+            (0, "0", "0"),
+            (1_000_000, "1M", "1M"),
+            (1_100_000, "2M", "1M"),
+            (1_900_000, "2M", "1M"),
+            (-1_100_000, "-1M", "-2M"),
+            (-1_900_000, "-1M", "-2M"),
+            (999_999, "1M", "999k"),
+        ];
+
+        fixtures.iter().for_each(|(case, ceil, floor)| {
+            assert_eq!(
+                abbrev_num_ceil(*case, Some(options)),
+                Some(ceil.to_string())
+            );
+            assert_eq!(
+                abbrev_num_floor(*case, Some(options)),
+                Some(floor.to_string())
+            );
+        });
+
+        let options = Options {
+            rounding_strategy: Some(RoundingStrategy::ToZero),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_num_ceil(1_210_000, Some(options)),
+            Some("1.3M".to_string())
+        );
+        assert_eq!(abbrev_num_floor(1_290_000, None), Some("1.2M".to_string()));
+    }
+
+    #[test]
+    fn can_abbreviate_rates() {
+        let fixtures: Vec<(u64, &str, &str)> = vec![
+            (0, "B/s", "0B/s"),
+            (999, "req/s", "999req/s"),
+            (1_500_000, "B/s", "1.5MB/s"),
+            (2_500, "req/s", "2.5kreq/s"),
+        ];
+
+        fixtures.iter().for_each(|(case, unit_suffix, expected)| {
+            let result = abbrev_rate(*case, unit_suffix, None);
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            space: true,
+            suffix: Some(" avg"),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_rate(2_500, "req/s", Some(options)),
+            Some("2.5k req/s avg".to_string())
+        );
+        assert_eq!(
+            abbrev_rate(-999, "req/s", Some(options)),
+            Some("-999 req/s avg".to_string())
+        );
+
+        let options = Options {
+            long_words: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_rate(3_000_000, "/day", Some(options)),
+            Some("3 million/day".to_string())
+        );
+
+        let options = Options {
+            space: true,
+            sign_position: SignPosition::Suffix,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_rate(-2_500, "req/s", Some(options)),
+            Some("2.5k req/s-".to_string())
+        );
+        assert_eq!(
+            abbrev_rate(
+                2_500,
+                "req/s",
+                Some(Options {
+                    unit_separator: Some("_"),
+                    width: Some(12),
+                    ..options
+                })
+            ),
+            Some("  2.5k_req/s".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_into_borrowed_strings() {
+        let fixtures: Vec<(isize, Cow<str>)> = vec![
+            (0, Cow::Borrowed("0")),
+            (7, Cow::Borrowed("7")),
+            (-7, Cow::Owned("-7".to_string())),
+            (999, Cow::Owned("999".to_string())),
+            (1_500, Cow::Owned("1.5k".to_string())),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_cow(*case, None);
+            assert_eq!(result, Some(expected.clone()));
+            assert_eq!(
+                matches!(result, Some(Cow::Borrowed(_))),
+                matches!(expected, Cow::Borrowed(_))
+            );
+        });
+
+        let options = Options {
+            width: Some(80),
+            fill: Some('·'),
+            ..Default::default()
+        };
+        let result = abbrev_cow(1_500, Some(options));
+
+        assert_eq!(
+            result.as_deref().map(str::chars).map(Iterator::count),
+            Some(80)
+        );
+        assert_eq!(
+            result.map(Cow::into_owned),
+            abbrev_i128(1_500, Some(options))
+        );
+        assert_eq!(
+            abbrev_cow(
+                0,
+                Some(Options {
+                    prefix: Some("$"),
+                    ..Default::default()
+                })
+            ),
+            Some(Cow::Owned("$0".to_string()))
+        );
+        assert_eq!(
+            abbrev_cow(
+                1_500_000,
+                Some(Options {
+                    abbreviations: Some(&["", "k"]),
+                    ..Default::default()
+                })
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn can_hide_zero_exponents() {
+        let fixtures: Vec<(NotationMode, isize, &str, &str)> = vec![
+            (NotationMode::Engineering, 150, "150e0", "150"),
+            (NotationMode::Engineering, -999, "-999e0", "-999"),
+            (NotationMode::Engineering, 1_500, "1.5e3", "1.5e3"),
+            (NotationMode::Scientific, 7, "7e0", "7"),
+            (NotationMode::Scientific, 70, "7e1", "7e1"),
+        ];
+
+        fixtures.iter().for_each(|(notation, case, shown, hidden)| {
+            let options = Options {
+                notation: *notation,
+                ..Default::default()
+            };
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(shown.to_string()));
+
+            let options = Options {
+                hide_zero_exponent: true,
+                ..options
+            };
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(hidden.to_string()));
+        });
+
+        let options = Options {
+            notation: NotationMode::Engineering,
+            hide_zero_exponent: true,
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_float(0.5, Some(options)), Some("500e-3".to_string()));
+    }
+
+    #[test]
+    fn can_force_sign() {
+        let options = Options {
+            force_sign: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> =
+            vec![(0, "0"), (7, "+7"), (1_500, "+1.5k"), (-1_500, "-1.5k")];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(abbrev_float(0.01, Some(options)), Some("0".to_string()));
+        assert_eq!(
+            abbrev_num(
+                0,
+                Some(Options {
+                    force_sign_zero: true,
+                    ..options
+                })
+            ),
+            Some("+0".to_string())
+        );
+        assert_eq!(
+            abbrev_num(
+                1_500,
+                Some(Options {
+                    prefix: Some("$"),
+                    sign_position: SignPosition::AfterPrefix,
+                    ..options
+                })
+            ),
+            Some("$+1.5k".to_string())
+        );
+        assert_eq!(
+            abbrev_num(
+                -1_500,
+                Some(Options {
+                    negative_style: NegativeStyle::Parentheses,
+                    ..options
+                })
+            ),
+            Some("(1.5k)".to_string())
+        );
+        assert_eq!(parse_abbrev("+1.5k", None), Some(1_500.0));
+    }
+
+    #[test]
+    fn can_declare_const_options() {
+        const OPTIONS: Options = Options {
+            precision: Some(2),
+            space: true,
+            ..Options::const_default()
+        };
+
+        assert_eq!(Options::const_default(), Options::default());
+        assert_eq!(abbrev_num(1_234, Some(OPTIONS)), Some("1.23 k".to_string()));
+    }
+
+    #[test]
+    fn can_abbreviate_with_scale() {
+        let fixtures: Vec<(isize, &str, u128)> = vec![
+            (0, "0", 1),
+            (150, "150", 1),
+            (-1_500, "-1.5k", 1_000),
+            (1_500_000, "1.5M", 1_000_000),
+            (999_999, "1M", 1_000_000),
+        ];
+
+        fixtures.iter().for_each(|(case, expected, divisor)| {
+            let result = abbrev_with_scale(*case, None);
+            assert_eq!(result, Some((expected.to_string(), *divisor)));
+        });
+
+        let fixtures: Vec<(Options, &str, u128)> = vec![
+            (
+                Options {
+                    base: Some(1024),
+                    ..Default::default()
+                },
+                "1.4M",
+                1_048_576,
+            ),
+            (
+                Options {
+                    fixed_unit: Some("k"),
+                    ..Default::default()
+                },
+                "1500k",
+                1_000,
+            ),
+            (
+                Options {
+                    unit_scale: Some(&[(1_000, "K"), (100_000, "L")]),
+                    ..Default::default()
+                },
+                "15L",
+                100_000,
+            ),
+            (
+                Options {
+                    notation: NotationMode::Scientific,
+                    ..Default::default()
+                },
+                "1.5e6",
+                1_000_000,
+            ),
+            (
+                Options {
+                    notation: NotationMode::Standard,
+                    ..Default::default()
+                },
+                "1500000",
+                1,
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, expected, divisor)| {
+            let result = abbrev_with_scale(1_500_000, Some(*options));
+            assert_eq!(result, Some((expected.to_string(), *divisor)));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_using_max_precision() {
+        let options = Options {
+            max_precision: Some(3),
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (999, "999"),
+            (1_000_000, "1M"),
+            (1_200_000, "1.2M"),
+            (1_234_567, "1.235M"),
+            (-1_234_567, "-1.235M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            min_precision: Some(1),
+            precision: Some(0),
+            keep_trailing_zeros: true,
+            ..options
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (1_000_000, "1.0M"),
+            (1_200_000, "1.2M"),
+            (1_234_567, "1.235M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            max_precision: Some(3),
+            max_width: Some(6),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_num(1_234_567, Some(options)),
+            Some("1.23M".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_round_numbers_like_decimals() {
+        let fixtures: Vec<Options> = vec![
+            Options::default(),
+            Options {
+                precision: Some(0),
+                min_precision: Some(2),
+                ..Default::default()
+            },
+            Options {
+                base: Some(1024),
+                ..Default::default()
+            },
+            Options {
+                long_words: true,
+                long_words_plural: Some(&["", "thousands", "millions"]),
+                ..Default::default()
+            },
+            Options {
+                min_abbrev_value: Some(10_000),
+                ..Default::default()
+            },
+            Options {
+                abbreviations: Some(&["", "k"]),
+                scientific_fallback: true,
+                ..Default::default()
+            },
+        ];
+        let numbers: Vec<i64> = vec![
+            1,
+            7,
+            -1_000,
+            2_000,
+            5_000,
+            1_024,
+            -3_000_000,
+            10_i64.pow(18),
+        ];
+
+        fixtures.iter().for_each(|options| {
+            numbers.iter().for_each(|number| {
+                assert_eq!(
+                    abbrev(*number, Some(*options)),
+                    abbrev_decimal(Decimal::from(*number), Some(*options))
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_using_rounding_modes() {
+        let fixtures: Vec<(Rounding, [&str; 4])> = vec![
+            (Rounding::HalfEven, ["2k", "-2k", "2k", "-3k"]),
+            (Rounding::HalfUp, ["3k", "-3k", "2k", "-3k"]),
+            (Rounding::Up, ["3k", "-2k", "3k", "-2k"]),
+            (Rounding::Down, ["2k", "-3k", "2k", "-3k"]),
+            (Rounding::TowardZero, ["2k", "-2k", "2k", "-2k"]),
+        ];
+
+        fixtures.iter().for_each(|(rounding, expected)| {
+            let options = Options {
+                precision: Some(0),
+                ..Default::default()
+            }
+            .with_rounding(*rounding);
+
+            [2_500, -2_500, 2_100, -2_900]
+                .iter()
+                .zip(expected)
+                .for_each(|(case, expected)| {
+                    let result = abbrev_num(*case, Some(options));
+                    assert_eq!(result, Some(expected.to_string()));
+                });
+        });
+
+        let options = Options::builder()
+            .precision(0)
+            .rounding(Rounding::HalfUp)
+            .build();
+
+        assert_eq!(abbrev_num(2_500, Some(options)), Some("3k".to_string()));
+        assert_eq!(
+            Options::default()
+                .with_rounding(Rounding::HalfEven)
+                .rounding_strategy,
+            Some(RoundingStrategy::MidpointNearestEven)
+        );
+    }
+
+    #[test]
+    fn can_clamp_to_max_unit() {
+        let options = Options {
+            clamp_to_max_unit: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(i128, &str)> = vec![
+            (1_500, "1.5k"),
+            (999_999_999_999_999_999_999_999_999, "1000Y"),
+            (-10_i128.pow(28), "-10000Y"),
+            (i128::MAX, "170141183460469.2Y"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_i128(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        let options = Options {
+            abbreviations: Some(&["", "k"]),
+            ..options
+        };
+
+        assert_eq!(
+            abbrev_num(2_500_000, Some(options)),
+            Some("2500k".to_string())
+        );
+        assert_eq!(
+            abbrev_float(2_500_000.5, Some(options)),
+            Some("2500k".to_string())
+        );
+        assert_eq!(unit_level(2_500_000, Some(options)), Some((1, "k")));
+        assert_eq!(
+            abbrev_num(
+                2_500_000,
+                Some(Options {
+                    scientific_fallback: true,
+                    ..options
+                })
+            ),
+            Some("2.5e6".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_using_precision_by_level() {
+        let options = Options {
+            precision_by_level: Some(&[0, 1, 2]),
+            ..Default::default()
+        };
+        let fixtures: Vec<(isize, &str)> = vec![
+            (999, "999"),
+            (1_234, "1.2k"),
+            (1_250_000, "1.25M"),
+            (-1_256_000, "-1.26M"),
+            (999_960, "1M"),
+            (1_234_567_890, "1.2B"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_num(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(abbrev_float(12.345, Some(options)), Some("12".to_string()));
+        assert_eq!(unit_level(999_960, Some(options)), Some((2, "M")));
+
+        let options = Options {
+            precision_by_level: Some(&[0, 1, 2]),
+            keep_trailing_zeros: true,
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_num(999, Some(options)), Some("999".to_string()));
+        assert_eq!(abbrev_num(2_000, Some(options)), Some("2.0k".to_string()));
+        assert_eq!(
+            abbrev_num(2_000_000, Some(options)),
+            Some("2.00M".to_string())
+        );
+        assert_eq!(
+            abbrev_num(999_999, Some(options)),
+            Some("1.00M".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_empty_units_without_separators() {
+        let fixtures: Vec<(Options, isize, &str)> = vec![
+            (
+                Options {
+                    space: true,
+                    suffix: Some("B"),
+                    ..Default::default()
+                },
+                150,
+                "150B",
+            ),
+            (
+                Options {
+                    space: true,
+                    suffix: Some("B"),
+                    ..Default::default()
+                },
+                1_500,
+                "1.5 kB",
+            ),
+            (
+                Options {
+                    unit_separator: Some("\u{00A0}"),
+                    suffix: Some("/s"),
+                    ..Default::default()
+                },
+                -150,
+                "-150/s",
+            ),
+            (
+                Options {
+                    long_words: true,
+                    prefix: Some("$"),
+                    ..Default::default()
+                },
+                150,
+                "$150",
+            ),
+            (
+                Options {
+                    abbreviations: Some(&["", "", "M"]),
+                    space: true,
+                    ..Default::default()
+                },
+                15_000,
+                "15",
+            ),
+            (
+                Options {
+                    space: true,
+                    width: Some(5),
+                    align: Align::Left,
+                    ..Default::default()
+                },
+                150,
+                "150  ",
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, case, expected)| {
+            let result = abbrev_num(*case, Some(*options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+    }
+
+    #[test]
+    fn cannot_abbreviate_above_max_magnitude() {
+        let options = Options {
+            max_magnitude: Some(1_000_000_000_000),
+            scientific_fallback: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, Option<&str>)> = vec![
+            (0, Some("0")),
+            (999_999_999_999, Some("1T")),
+            (1_000_000_000_000, Some("1T")),
+            (-1_000_000_000_000, Some("-1T")),
+            (1_000_000_000_001, None),
+            (-1_500_000_000_000, None),
+            (i64::MAX, None),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev(*case, Some(options));
+            assert_eq!(result, expected.map(|value| value.to_string()));
+        });
+
+        assert_eq!(abbrev_i128(i128::MIN, Some(options)), None);
+        assert_eq!(abbrev_float(1e12, Some(options)), Some("1T".to_string()));
+        assert_eq!(abbrev_float(1e12 + 0.5, Some(options)), None);
+        assert_eq!(
+            try_abbrev_num(
+                -2_000,
+                Some(Options {
+                    max_magnitude: Some(1_999),
+                    notation: NotationMode::Standard,
+                    ..Default::default()
+                })
+            ),
+            Err(AbbrevError::AboveMaxMagnitude)
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_using_nice_numbers() {
+        let options = Options {
+            nice_numbers: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, &str)> = vec![
+            (0, "0"),
+            (7, "5"),
+            (150, "100"),
+            (1_000, "1k"),
+            (1_234_000, "1M"),
+            (1_700_000, "2M"),
+            (2_300_000, "2.5M"),
+            (-3_900_000, "-5M"),
+            (8_000_000, "10M"),
+            (21_000_000, "20M"),
+            (24_000_000, "25M"),
+            (750_000_000, "500M"),
+            (800_000_000, "1B"),
+            (999_999, "1M"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()), "{case}");
+        });
+
+        assert_eq!(abbrev_float(0.37, Some(options)), Some("0.25".to_string()));
+
+        let options = Options {
+            notation: NotationMode::Scientific,
+            ..options
+        };
+
+        assert_eq!(
+            abbrev_num(3_300_000, Some(options)),
+            Some("2.5e6".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_within_mantissa_width() {
+        let options = Options {
+            mantissa_width: Some(4),
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, &str)> = vec![
+            (0, "0"),
+            (7, "7"),
+            (999, "999"),
+            (1_234, "1.23k"),
+            (-1_234_567, "-1.23M"),
+            (12_345_678, "12.3M"),
+            (123_456_789, "123M"),
+            (999_999_999, "1B"),
+            (1_005_000_000_000, "1T"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()), "{case}");
+        });
+
+        let fixtures: Vec<(Options, f64, &str)> = vec![
+            (options, 12.3456, "12.3"),
+            (
+                Options {
+                    precision: Some(1),
+                    ..options
+                },
+                1_234_567.0,
+                "1.2M",
+            ),
+            (
+                Options {
+                    mantissa_width: Some(2),
+                    ..options
+                },
+                1_234_567.0,
+                "1M",
+            ),
+            (
+                Options {
+                    mantissa_width: Some(5),
+                    max_width: Some(5),
+                    ..options
+                },
+                -1_234_567.0,
+                "-1.2M",
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, case, expected)| {
+            let result = abbrev_float(*case, Some(*options));
+            assert_eq!(result, Some(expected.to_string()), "{case}");
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_using_multi_character_units() {
+        let options = Options {
+            abbreviations: Some(&["", "Tsd.", "Mio.", "Mrd."]),
+            decimal_separator: Some(','),
+            group_separator: Some('.'),
+            space: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(Options, i64, &str)> = vec![
+            (options, 999, "999"),
+            (options, 1_500, "1,5 Tsd."),
+            (options, 1_500_000, "1,5 Mio."),
+            (options, -1_200_000_000, "-1,2 Mrd."),
+            (
+                Options {
+                    clamp_to_max_unit: true,
+                    ..options
+                },
+                2_500_000_000_000,
+                "2.500 Mrd.",
+            ),
+            (
+                Options {
+                    width: Some(10),
+                    ..options
+                },
+                -1_500_000,
+                " -1,5 Mio.",
+            ),
+            (
+                Options {
+                    max_width: Some(10),
+                    precision: Some(3),
+                    ..options
+                },
+                1_234_567,
+                "1,23 Mio.",
+            ),
+            (
+                Options {
+                    force_sign: true,
+                    suffix: Some(" €"),
+                    ..options
+                },
+                1_500,
+                "+1,5 Tsd. €",
+            ),
+            (
+                Options {
+                    abbreviations: options.abbreviations,
+                    ..Options::for_locale(Locale::DeDe)
+                },
+                1_500,
+                "1,5\u{00A0}Tsd.",
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, case, expected)| {
+            let result = abbrev(*case, Some(*options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(parse_abbrev("1,5 Mio.", Some(options)), Some(1_500_000.0));
+    }
+
+    #[test]
+    fn can_abbreviate_saturated_numbers() {
+        let options = Options {
+            saturate_at: Some(999_000),
+            ..Default::default()
+        };
+        let fixtures: Vec<(Options, i64, &str)> = vec![
+            (options, 150, "150"),
+            (options, 999_000, "999k"),
+            (options, 999_001, "999k+"),
+            (options, 25_000_000, "999k+"),
+            (options, -25_000_000, "-999k+"),
+            (options, i64::MAX, "999k+"),
+            (
+                Options {
+                    saturate_at: Some(99),
+                    saturate_suffix: Some("+ more"),
+                    ..options
+                },
+                1_500,
+                "99+ more",
+            ),
+            (
+                Options {
+                    prefix: Some("$"),
+                    suffix: Some(" USD"),
+                    space: true,
+                    width: Some(12),
+                    ..options
+                },
+                5_000_000,
+                " $999 k+ USD",
+            ),
+            (
+                Options {
+                    saturate_at: Some(1_500),
+                    abbreviations: Some(&["", "k"]),
+                    ..options
+                },
+                i64::MIN,
+                "-1.5k+",
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, case, expected)| {
+            let result = abbrev(*case, Some(*options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(
+            abbrev_float(-1e20, Some(options)),
+            Some("-999k+".to_string())
+        );
+        assert_eq!(
+            abbrev_i128(i128::MIN, Some(options)),
+            Some("-999k+".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_using_base_level_offset() {
+        let options = Options {
+            base_level_offset: Some(1),
+            ..Default::default()
+        };
+        let fixtures: Vec<(Options, i64, Option<&str>)> = vec![
+            (options, 0, Some("0k")),
+            (options, 5, Some("5k")),
+            (options, -999, Some("-999k")),
+            (options, 1_500, Some("1.5M")),
+            (options, 2_500_000, Some("2.5B")),
+            (options, 999_999, Some("1B")),
+            (
+                Options {
+                    base_level_offset: Some(2),
+                    long_words: true,
+                    space: true,
+                    long_words_plural: Some(&["", "thousands", "millions", "billions"]),
+                    ..options
+                },
+                1_500,
+                Some("1.5 billions"),
+            ),
+            (
+                Options {
+                    base_level_offset: Some(2),
+                    abbreviations: Some(&["", "k", "M"]),
+                    ..options
+                },
+                12,
+                Some("12M"),
+            ),
+            (
+                Options {
+                    base_level_offset: Some(2),
+                    abbreviations: Some(&["", "k", "M"]),
+                    ..options
+                },
+                1_200,
+                None,
+            ),
+            (
+                Options {
+                    base_level_offset: Some(3),
+                    abbreviations: Some(&["", "k", "M"]),
+                    ..options
+                },
+                5,
+                None,
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, case, expected)| {
+            let result = abbrev(*case, Some(*options));
+            assert_eq!(result, expected.map(|value| value.to_string()));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_using_negative_base_level_offset() {
+        let options = Options::builder().base_level_offset(-1).build();
+        let short = Options {
+            base_level_offset: Some(-2),
+            abbreviations: Some(&["", "k"]),
+            ..options
+        };
+        let fixtures: Vec<(Options, i64, Option<&str>)> = vec![
+            (options, 0, None),
+            (options, 999, None),
+            (options, 1_500, Some("1.5")),
+            (options, -2_500_000, Some("-2.5k")),
+            (options, 999_999, Some("1k")),
+            (options, 1_500_000_000_000_000_000, Some("1.5P")),
+            (
+                Options {
+                    fixed_unit: Some("k"),
+                    ..options
+                },
+                260_000,
+                Some("0.3k"),
+            ),
+            (short, 1_500_000, Some("1.5")),
+            (short, 1_500_000_000, Some("1.5k")),
+            (short, 1_500_000_000_000, None),
+            (short, 1_500, None),
+        ];
+
+        fixtures.iter().for_each(|(options, case, expected)| {
+            let result = abbrev(*case, Some(*options));
+            assert_eq!(result, expected.map(|value| value.to_string()), "{case}");
+        });
+
+        assert_eq!(unit_level(2_500_000, Some(options)), Some((2, "k")));
+    }
+
+    #[test]
+    fn can_abbreviate_using_round_threshold() {
+        let options = Options {
+            round_threshold: Some(Decimal::new(6, 1)),
+            precision: Some(0),
+            ..Default::default()
+        };
+        let fixtures: Vec<(Options, i64, &str)> = vec![
+            (options, 1_550_000, "1M"),
+            (options, 1_650_000, "2M"),
+            (options, 1_599_999, "1M"),
+            (options, 1_600_000, "2M"),
+            (options, -1_550_000, "-1M"),
+            (options, -1_650_000, "-2M"),
+            (options, 999_700, "1M"),
+            (
+                Options {
+                    precision: Some(1),
+                    ..options
+                },
+                1_250_000,
+                "1.2M",
+            ),
+            (
+                Options {
+                    precision: Some(1),
+                    ..options
+                },
+                1_260_000,
+                "1.3M",
+            ),
+            (
+                Options {
+                    rounding_strategy: Some(RoundingStrategy::AwayFromZero),
+                    ..options
+                },
+                1_100_000,
+                "1M",
+            ),
+            (
+                Options {
+                    precision: None,
+                    significant_digits: Some(2),
+                    ..options
+                },
+                1_550_000,
+                "1.5M",
+            ),
+            (
+                Options {
+                    precision: None,
+                    significant_digits: Some(1),
+                    ..options
+                },
+                27_000,
+                "30k",
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, case, expected)| {
+            let result = abbrev(*case, Some(*options));
+            assert_eq!(result, Some(expected.to_string()), "{case}");
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_with_unit_before_mantissa() {
+        let options = Options {
+            unit_before: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(Options, i64, &str)> = vec![
+            (options, 150, "150"),
+            (options, -1_500, "-k1.5"),
+            (options, 2_500_000, "M2.5"),
+            (
+                Options {
+                    abbreviations: Some(&["", "K$", "M$"]),
+                    prefix: Some("~"),
+                    space: true,
+                    suffix: Some(" total"),
+                    ..options
+                },
+                -1_500_000,
+                "-~M$ 1.5 total",
+            ),
+            (
+                Options {
+                    sign_position: SignPosition::Suffix,
+                    ..options
+                },
+                -1_500,
+                "k1.5-",
+            ),
+            (
+                Options {
+                    width: Some(6),
+                    ..options
+                },
+                1_500,
+                "  k1.5",
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, case, expected)| {
+            let result = abbrev(*case, Some(*options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_using_step_of_one() {
+        const LABELS: [&str; 19] = [
+            "", "_e1", "_e2", "_e3", "_e4", "_e5", "_e6", "_e7", "_e8", "_e9", "_e10", "_e11",
+            "_e12", "_e13", "_e14", "_e15", "_e16", "_e17", "_e18",
+        ];
+        let options = Options {
+            step: Some(1),
+            abbreviations: Some(&LABELS),
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, &str)> = vec![
+            (0, "0"),
+            (7, "7"),
+            (10, "1_e1"),
+            (45, "4.5_e1"),
+            (4_500, "4.5_e3"),
+            (-4_500, "-4.5_e3"),
+            (99, "9.9_e1"),
+            (996, "1_e3"),
+            (123_456_789, "1.2_e8"),
+            (i64::MAX, "9.2_e18"),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev(*case, Some(options));
+            assert_eq!(result, Some(expected.to_string()), "{case}");
+        });
+
+        assert_eq!(abbrev_i128(10_i128.pow(19), Some(options)), None);
+        assert_eq!(
+            abbrev_float(4_500.5, Some(options)),
+            Some("4.5_e3".to_string())
+        );
+        assert_eq!(unit_level(4_500, Some(options)), Some((3, "_e3")));
+    }
+
+    #[test]
+    fn can_abbreviate_without_unit_promotion() {
+        let options = Options {
+            no_unit_promotion: true,
+            ..Default::default()
+        };
+        let fixtures: Vec<(Options, i64, &str)> = vec![
+            (options, 999, "999"),
+            (options, 1_999_999, "1.9M"),
+            (options, -1_999_999, "-1.9M"),
+            (options, 999_999, "999.9k"),
+            (
+                Options {
+                    precision: Some(0),
+                    ..options
+                },
+                1_999_999,
+                "1M",
+            ),
+            (
+                Options {
+                    rounding_strategy: Some(RoundingStrategy::AwayFromZero),
+                    round_threshold: Some(Decimal::new(1, 1)),
+                    ..options
+                },
+                1_999_999,
+                "1.9M",
+            ),
+            (
+                Options {
+                    significant_digits: Some(1),
+                    ..options
+                },
+                999_999,
+                "900k",
+            ),
+            (
+                Options {
+                    nice_numbers: true,
+                    ..options
+                },
+                4_900_000,
+                "2.5M",
+            ),
+            (
+                Options {
+                    unit_scale: Some(&[(1_000, "K"), (100_000, "L")]),
+                    ..options
+                },
+                99_999,
+                "99.9K",
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, case, expected)| {
+            let result = abbrev(*case, Some(*options));
+            assert_eq!(result, Some(expected.to_string()), "{case}");
+        });
+
+        let numbers = (1..10).map(|digits| 10_isize.pow(digits) - 1);
+
+        numbers.for_each(|number| {
+            let level = number.ilog10() / 3;
+            let result = unit_level(number, Some(options));
+            assert_eq!(result, Some((level, DEFAULT_ABBREVIATIONS[level as usize])));
+        });
+    }
+
+    #[test]
+    fn can_validate_abbreviation_units() {
+        let fixtures: Vec<(Options, Result<(), AbbrevError>)> = vec![
+            (Options::default(), Ok(())),
+            (
+                Options {
+                    long_words: true,
+                    ..Default::default()
+                },
+                Ok(()),
+            ),
+            (
+                Options {
+                    abbreviations: Some(&["", "k", "M", "k"]),
+                    ..Default::default()
+                },
+                Err(AbbrevError::DuplicateUnit { level: 3 }),
+            ),
+            (
+                Options {
+                    abbreviations: Some(&["", "k", ""]),
+                    ..Default::default()
+                },
+                Err(AbbrevError::EmptyUnit { level: 2 }),
+            ),
+            (
+                Options {
+                    abbreviations: Some(&["k", "M"]),
+                    ..Default::default()
+                },
+                Ok(()),
+            ),
+            (
+                Options {
+                    abbreviations: Some(&["", "k", "M"]),
+                    base_level_offset: Some(1),
+                    ..Default::default()
+                },
+                Ok(()),
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, expected)| {
+            assert_eq!(options.validated().map(|_| ()), *expected);
+        });
+
+        assert_eq!(
+            AbbrevError::DuplicateUnit { level: 3 }.to_string(),
+            "duplicate abbreviation unit at level 3"
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_assuming_unit() {
+        let options = Options {
+            assume_unit: Some("M"),
+            ..Default::default()
+        };
+        let fixtures: Vec<(Options, i64, &str)> = vec![
+            (options, 0, "0M"),
+            (options, 5, "5M"),
+            (options, 1_250, "1250M"),
+            (options, -1_250, "-1250M"),
+            (
+                Options {
+                    group_separator: Some(','),
+                    space: true,
+                    ..options
+                },
+                1_250_000,
+                "1,250,000 M",
+            ),
+            (
+                Options {
+                    fixed_unit: Some("k"),
+                    notation: NotationMode::Scientific,
+                    ..options
+                },
+                1_500,
+                "1500M",
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, case, expected)| {
+            let result = abbrev(*case, Some(*options));
+            assert_eq!(result, Some(expected.to_string()));
+        });
+
+        assert_eq!(abbrev_float(2.345, Some(options)), Some("2.3M".to_string()));
+        assert_eq!(
+            abbrev_i128(i128::MIN, Some(options)),
+            Some(format!("{}M", i128::MIN))
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_scaled_by_factor() {
+        let options = Options {
+            precision: Some(2),
+            group_separator: Some(','),
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, u128, Option<Options>, Option<&str>)> = vec![
+            (1_500_000, 1_000, None, Some("1500")),
+            (0, 1_000, None, Some("0")),
+            (999, 1, None, Some("999")),
+            (-1_234_567, 1_000, Some(options), Some("-1,234.57")),
+            (7, 1_000, None, Some("0")),
+            (7, 1_000, Some(options), Some("0.01")),
+            (1_500_000_000, 1_000_000, Some(options), Some("1,500")),
+            (1_500, 0, None, None),
+        ];
+
+        fixtures
+            .iter()
+            .for_each(|(case, factor, options, expected)| {
+                let result = abbrev_scaled(*case, *factor, *options);
+                assert_eq!(result, expected.map(|value| value.to_string()));
+            });
+
+        assert_eq!(abbrev_scaled(u128::MAX, 1, None), None);
+        assert_eq!(
+            abbrev_scaled(u128::MAX, 10_u128.pow(30), None),
+            Some("340282366.9".to_string())
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_with_clamped_precision() {
+        let options = Options {
+            clamp_precision: true,
+            precision: Some(5),
+            keep_trailing_zeros: true,
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev(1_500, Some(options)), Some("1.500k".to_string()));
+        assert_eq!(abbrev(-7, Some(options)), Some("-7".to_string()));
+        assert_eq!(
+            abbrev(1_234_567_890, Some(options)),
+            Some("1.23457B".to_string())
+        );
+        let floats = Options {
+            precision: Some(10),
+            ..options
+        };
+
+        assert_eq!(
+            abbrev_float(7.123456, Some(floats)),
+            Some("7.123456".to_string())
+        );
+        assert_eq!(
+            abbrev_float(1_234.5, Some(floats)),
+            Some("1.2345k".to_string())
+        );
+        assert_eq!(
+            abbrev_float(0.1 + 0.2, Some(floats)),
+            Some("0.3".to_string())
+        );
+        assert_eq!(abbrev_f32(7.1234, Some(floats)), Some("7.1234".to_string()));
+        assert_eq!(
+            abbrev_float(
+                7.123456,
+                Some(Options {
+                    clamp_precision: false,
+                    ..floats
+                })
+            ),
+            Some("7.1234560000".to_string())
+        );
+        assert_eq!(
+            abbrev(
+                1_500,
+                Some(Options {
+                    keep_trailing_zeros: false,
+                    min_precision: Some(5),
+                    ..options
+                })
+            ),
+            Some("1.500k".to_string())
+        );
+        assert_eq!(
+            abbrev(
+                1_566,
+                Some(Options {
+                    precision: Some(2),
+                    ..options
+                })
+            ),
+            Some("1.57k".to_string())
+        );
+        assert_eq!(
+            abbrev(
+                1_500,
+                Some(Options {
+                    clamp_precision: false,
+                    ..options
+                })
+            ),
+            Some("1.50000k".to_string())
+        );
+    }
+
+    #[test]
+    fn can_get_unit_level_like_abbrev_num() {
+        let fixtures: Vec<(Options, isize, u32, &str)> = vec![
+            (Options::default(), 1_500_000, 2, "M"),
+            (Options::for_locale(Locale::EnIn), 1_500_000, 2, "L"),
+            (Options::for_locale(Locale::EnIn), 999, 0, ""),
+            (
+                Options {
+                    fixed_unit: Some("k"),
+                    ..Default::default()
+                },
+                1_500_000,
+                1,
+                "k",
+            ),
+            (
+                Options {
+                    assume_unit: Some("M"),
+                    ..Default::default()
+                },
+                1_500,
+                0,
+                "M",
+            ),
+            (
+                Options {
+                    notation: NotationMode::Standard,
+                    ..Default::default()
+                },
+                1_500_000,
+                0,
+                "",
+            ),
+            (
+                Options {
+                    notation: NotationMode::Scientific,
+                    ..Default::default()
+                },
+                1_500_000,
+                0,
+                "",
+            ),
+        ];
+
+        fixtures.iter().for_each(|(options, case, level, unit)| {
+            let result = unit_level(*case, Some(*options));
+            assert_eq!(result, Some((*level, *unit)), "{case}");
+
+            let written = abbrev_num(*case, Some(*options)).unwrap();
+            assert!(written.ends_with(unit), "{written}");
+        });
+
+        assert_eq!(
+            abbrev_num(1_500_000, Some(Options::for_locale(Locale::EnIn))),
+            Some("15L".to_string())
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn can_dereference_deprecated_abbreviations() {
+        let units: &[&str; 9] = &ABBREVIATIONS;
+
+        assert_eq!(*ABBREVIATIONS, DEFAULT_ABBREVIATIONS);
+        assert_eq!(&*ABBREVIATIONS, units);
+        assert_eq!(ABBREVIATIONS[1], "k");
+        assert_eq!(&ABBREVIATIONS[..], &DEFAULT_ABBREVIATIONS[..]);
+    }
+
+    #[test]
+    fn can_write_floats_beyond_decimal_range() {
+        let fallback = Options {
+            scientific_fallback: true,
+            ..Default::default()
+        };
+        let scientific = Options {
+            notation: NotationMode::Scientific,
+            ..Default::default()
+        };
+        let engineering = Options {
+            notation: NotationMode::Engineering,
+            ..Default::default()
+        };
+        let fixtures: Vec<(f64, Options, Option<&str>)> = vec![
+            (1e30, fallback, Some("1e30")),
+            (-2.54e35, fallback, Some("-2.5e35")),
+            (9.96e40, fallback, Some("1e41")),
+            (f64::MAX, fallback, Some("1.8e308")),
+            (1e30, Options::default(), None),
+            (
+                1e30,
+                Options {
+                    max_magnitude: Some(10_u128.pow(29)),
+                    ..fallback
+                },
+                None,
+            ),
+            (2.5e31, engineering, Some("25e30")),
+            (1e-30, scientific, Some("1e-30")),
+            (-1.55e-30, scientific, Some("-1.6e-30")),
+            (2.5e-31, engineering, Some("250e-33")),
+            (5e-324, scientific, Some("5e-324")),
+            (1e-30, Options::default(), Some("0")),
+            (1e-30, fallback, Some("0")),
+        ];
+
+        fixtures.iter().for_each(|(number, options, expected)| {
+            assert_eq!(
+                abbrev_float(*number, Some(*options)),
+                expected.map(String::from),
+                "{number:e}"
+            );
+        });
+
+        assert_eq!(
+            abbrev_f32(f32::MAX, Some(fallback)),
+            Some("3.4e38".to_string())
+        );
+        assert_eq!(
+            abbrev_f32(
+                f32::MAX,
+                Some(Options {
+                    precision: Some(8),
+                    ..fallback
+                })
+            ),
+            Some("3.4028235e38".to_string())
+        );
+        assert_eq!(
+            abbrev_f32(-1e-40, Some(scientific)),
+            Some("-1e-40".to_string())
+        );
     }
 }
@@ -0,0 +1,82 @@
+/// Abbreviates a number with [abbrev_num](crate::abbrev_num), setting the [Options]
+/// fields by name.
+///
+/// Each field is set through its [OptionsBuilder](crate::OptionsBuilder) method, so
+/// optional fields take their inner value. The fields may be given in any order, and
+/// `rounding` is accepted as a shorthand for a [RoundingStrategy](crate::RoundingStrategy)
+/// variant.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev;
+///
+/// assert_eq!(abbrev!(1_500), Some("1.5k".to_string()));
+/// assert_eq!(abbrev!(1_566_450, precision = 2, rounding = ToZero), Some("1.56M".to_string()));
+/// assert_eq!(abbrev!(1_500, space = true, suffix = " USD"), Some("1.5 k USD".to_string()));
+/// ```
+#[macro_export]
+macro_rules! abbrev {
+    ($number:expr $(,)?) => {
+        $crate::abbrev_num($number, None)
+    };
+    ($number:expr, $($options:tt)+) => {
+        $crate::abbrev_num(
+            $number,
+            Some($crate::__abbrev_options!($crate::Options::builder(); $($options)+)),
+        )
+    };
+}
+
+/// Builds the [Options] for [abbrev], one field at a time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __abbrev_options {
+    ($builder:expr;) => {
+        $builder.build()
+    };
+    ($builder:expr; rounding = $strategy:ident $(, $($rest:tt)*)?) => {
+        $crate::__abbrev_options!(
+            $builder.rounding_strategy($crate::RoundingStrategy::$strategy);
+            $($($rest)*)?
+        )
+    };
+    ($builder:expr; $field:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__abbrev_options!($builder.$field($value); $($($rest)*)?)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{abbrev_num, Options, RoundingStrategy};
+
+    #[test]
+    fn can_abbreviate_using_macro() {
+        assert_eq!(abbrev!(1_500), abbrev_num(1_500, None));
+        assert_eq!(abbrev!(-2_300_000,), abbrev_num(-2_300_000, None));
+
+        let options = Options {
+            precision: Some(2),
+            rounding_strategy: Some(RoundingStrategy::ToZero),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev!(1_566_450, precision = 2, rounding = ToZero),
+            abbrev_num(1_566_450, Some(options))
+        );
+        assert_eq!(
+            abbrev!(1_566_450, rounding = ToZero, precision = 2,),
+            abbrev_num(1_566_450, Some(options))
+        );
+        assert_eq!(
+            abbrev!(
+                1_566_450,
+                rounding_strategy = RoundingStrategy::ToZero,
+                precision = 1 + 1
+            ),
+            abbrev_num(1_566_450, Some(options))
+        );
+        assert_eq!(abbrev!(1_000_000, abbreviations = &["", "k"]), None);
+    }
+}
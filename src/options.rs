@@ -0,0 +1,550 @@
+use crate::{abbreviations, first_level, AbbrevError, Decimal, RoundingStrategy, ScaleTable};
+
+/// The options for abbreviating a number.
+///
+/// With the `serde` feature enabled, the options can be serialized and deserialized.
+/// The borrowed fields are skipped when deserializing, as they cannot be borrowed from
+/// the input. Deserialize [OwnedOptions](crate::OwnedOptions) instead to keep them.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Options<'a> {
+    /// The precision of the result, as the number of decimal places. `1` by default.
+    pub precision: Option<u32>,
+    /// The minimum number of decimal places of the result, padded with trailing zeros.
+    /// Trailing zeros are removed by default.
+    pub min_precision: Option<u32>,
+    /// A list of custom abbreviation units.
+    /// [DEFAULT_ABBREVIATIONS](crate::DEFAULT_ABBREVIATIONS) is used by default, or
+    /// [LONG_ABBREVIATIONS](crate::LONG_ABBREVIATIONS) when [Options::long_words] is set.
+    /// The unit at index `n` is used for values in the range of `base^n` to `base^(n + 1)`.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub abbreviations: Option<&'a [&'a str]>,
+    /// The [RoundingStrategy] to use on the result.
+    /// [RoundingStrategy::MidpointNearestEven] is used by default.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::rounding_strategy")
+    )]
+    pub rounding_strategy: Option<RoundingStrategy>,
+    /// A list of units for non-zero values below one, such as [SMALL_ABBREVIATIONS](crate::SMALL_ABBREVIATIONS).
+    /// The unit at index `n` is used for values in the range of `base^-(n + 1)` to
+    /// `base^-n`. Values below one are not abbreviated by default.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub small_units: Option<&'a [&'a str]>,
+    /// The factor between consecutive abbreviation units, which must be at least `2`.
+    /// `10^step` by default.
+    pub base: Option<u32>,
+    /// The number of digits between consecutive abbreviation units, which must be at
+    /// least `1`. This is ignored when [Options::base] is set. `3` by default.
+    pub step: Option<u32>,
+    /// Whether to insert a space between the number and a non-empty unit. `false` by
+    /// default.
+    pub space: bool,
+    /// Whether to use full-word units such as `million` instead of letters. This implies
+    /// [Options::space]. `false` by default.
+    pub long_words: bool,
+    /// The character separating the integer and fractional parts of the number. `.` by
+    /// default.
+    pub decimal_separator: Option<char>,
+    /// The character inserted between every three digits of the integer part of the
+    /// number, such as `999,999`. Digits are not grouped by default.
+    pub group_separator: Option<char>,
+    /// The magnitude below which numbers are not abbreviated, such as `10_000` to keep
+    /// `5000` as is. Numbers are abbreviated from the first unit onwards by default.
+    pub min_abbrev_value: Option<usize>,
+    /// The text written before the number, such as a currency symbol.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub prefix: Option<&'a str>,
+    /// The text written after the number and its unit. It directly follows numbers with
+    /// an empty unit, such as `150B`, as no separator is written for an empty unit.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub suffix: Option<&'a str>,
+    /// The position of the sign relative to [Options::prefix] or the unit. This is
+    /// ignored for [NegativeStyle::Parentheses].
+    pub sign_position: SignPosition,
+    /// Whether to write numbers beyond the last abbreviation unit in scientific notation,
+    /// such as `1e22`, instead of failing. Floats too large for a decimal, such as `1e30`,
+    /// are written this way as well. `false` by default.
+    pub scientific_fallback: bool,
+    /// The minimum number of characters of the result, which is padded with
+    /// [Options::fill] up to this width. The result is never truncated.
+    pub width: Option<usize>,
+    /// The character used to pad the result up to [Options::width]. A space by default.
+    pub fill: Option<char>,
+    /// The side of the padding, which aligns the result within [Options::width].
+    pub align: Align,
+    /// The notation of the result, which selects between abbreviation units, plain
+    /// numbers and exponents.
+    pub notation: NotationMode,
+    /// The [RoundingStrategy] to use on the result of negative numbers, in place of
+    /// [Options::rounding_strategy]. Like it, the strategy applies to the absolute value,
+    /// so [RoundingStrategy::MidpointTowardZero] rounds `-1.5` to `-1`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::rounding_strategy")
+    )]
+    pub negative_rounding_strategy: Option<RoundingStrategy>,
+    /// The text inserted between the number and a non-empty unit, such as a non-breaking
+    /// space. This supersedes [Options::space] and [Options::long_words].
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub unit_separator: Option<&'a str>,
+    /// The style of negative numbers, such as a leading minus sign or parentheses.
+    pub negative_style: NegativeStyle,
+    /// Whether to write exponents as a `×10` multiplier with superscript digits, such as
+    /// `1.5×10³`, instead of `1.5e3`. `false` by default.
+    pub superscript_exponent: bool,
+    /// The number of significant digits of the result, which overrides
+    /// [Options::precision] when set.
+    pub significant_digits: Option<u32>,
+    /// Whether to keep the trailing zeros of the result, so that it always has exactly
+    /// [Options::precision] decimal places, such as `1.00M`. `false` by default.
+    pub keep_trailing_zeros: bool,
+    /// The text written in place of zero values along with their prefix and suffix, such
+    /// as `—`.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub zero_text: Option<&'a str>,
+    /// A list of plural forms parallel to the abbreviation units, used unless the
+    /// rounded number is exactly one, such as `grands` for `grand`. Units without a
+    /// plural form are left as is.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub long_words_plural: Option<&'a [&'a str]>,
+    /// A list of `(threshold, unit)` breakpoints in ascending order, which overrides
+    /// [Options::abbreviations] and the base. The number is divided by the highest
+    /// threshold that does not exceed it, such as `(100_000, "L")` for lakh, so each step
+    /// can use a different base. Numbers below the first threshold are not abbreviated.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub unit_scale: Option<&'a [(u128, &'a str)]>,
+    /// The naming scale of the full-word units used with [Options::long_words]. The
+    /// letter units are unaffected.
+    pub scale: NumberScale,
+    /// The abbreviation unit that every number is written in, instead of selecting one by
+    /// its magnitude. Numbers below the unit have a fractional mantissa, such as `0.5M`.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub fixed_unit: Option<&'a str>,
+    /// The maximum number of characters of the result, including its sign and unit, such
+    /// as `5` to write `1_234_567` as `1.2M`. Room for the sign is kept for positive
+    /// numbers too. The precision is reduced from [Options::max_precision] or
+    /// [Options::precision], or from the highest precision when unset, until the result
+    /// fits or the precision reaches zero.
+    pub max_width: Option<usize>,
+    /// Whether to leave out an exponent of zero in scientific and engineering notation,
+    /// such as `150` instead of `150e0`. `false` by default.
+    pub hide_zero_exponent: bool,
+    /// Whether to write a `+` sign before positive non-zero numbers, such as `+1.5k`.
+    /// `false` by default.
+    pub force_sign: bool,
+    /// Whether to write zero with a `+` sign as well when [Options::force_sign] is set,
+    /// such as `+0`. `false` by default.
+    pub force_sign_zero: bool,
+    /// The maximum number of decimal places of the result, which overrides
+    /// [Options::precision]. Trailing zeros are always removed, even with
+    /// [Options::keep_trailing_zeros], so that [Options::min_precision] alone sets the
+    /// minimum.
+    pub max_precision: Option<u32>,
+    /// Whether to write numbers beyond the last abbreviation unit in that unit with a
+    /// large mantissa, such as `10000Y`, instead of failing. This is ignored when
+    /// [Options::scientific_fallback] is set. `false` by default.
+    pub clamp_to_max_unit: bool,
+    /// A list of precisions parallel to the abbreviation units, which overrides
+    /// [Options::precision] for the units it covers, such as `&[1, 1, 2]` for `1.5k` and
+    /// `1.25M`. This is ignored when [Options::max_width] or [Options::mantissa_width] is
+    /// set.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub precision_by_level: Option<&'a [u32]>,
+    /// Whether [abbrev_bits](crate::abbrev_bits) writes bit rates with a `bit/s` suffix,
+    /// such as `1.5Mbit/s`, instead of `bps`. `false` by default.
+    pub long_bit_rate: bool,
+    /// Whether to write at least one fractional digit for numbers scaled down to a unit,
+    /// such as `1.0M` instead of `1M`, regardless of the precision. Numbers that are not
+    /// scaled down are unaffected. `false` by default.
+    pub always_decimal: bool,
+    /// The grouping of the integer digits with [Options::group_separator], such as
+    /// `1,234,567` or the Indian `12,34,567`.
+    pub grouping: Grouping,
+    /// Whether to group the fractional digits with [Options::group_separator] as well,
+    /// in groups of three from the decimal separator, such as `1.234 567M`. `false` by
+    /// default.
+    pub group_fraction: bool,
+    /// The largest absolute value that is abbreviated, such as `1_000_000_000_000`. Larger
+    /// numbers fail with [AbbrevError::AboveMaxMagnitude](crate::AbbrevError::AboveMaxMagnitude)
+    /// before they are scaled, regardless of [Options::scientific_fallback] and
+    /// [Options::clamp_to_max_unit].
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::wide"))]
+    pub max_magnitude: Option<u128>,
+    /// The precomputed divisors of every abbreviation level, which are used in place of
+    /// computing them for each number when the table has the same base.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub scale_table: Option<&'a ScaleTable>,
+    /// Whether to snap the mantissa to the nearest "nice" value of `1`, `2`, `2.5` or `5`
+    /// times a power of ten, such as `2M` for `1_700_000`, instead of rounding it to a
+    /// precision. Numbers below the first unit are snapped as well, such as `100` for
+    /// `150`. This is useful for the labels of chart axes. `false` by default.
+    pub nice_numbers: bool,
+    /// The maximum number of characters of the mantissa alone, without the sign and the
+    /// unit, such as `4` for `1.23M` and `12.3M`. The precision is reduced until the
+    /// mantissa fits, starting from [Options::precision] if set. Mantissas are never
+    /// truncated past a precision of zero.
+    pub mantissa_width: Option<usize>,
+    /// Whether the trailing zeros of the result are trimmed or kept to exactly
+    /// [Options::precision] decimal places. [Normalization::Fixed] is equivalent to
+    /// [Options::keep_trailing_zeros].
+    pub normalization: Normalization,
+    /// The largest absolute value written as is. Larger numbers are written as this
+    /// value followed by [Options::saturate_suffix], such as `999k+` for a value of
+    /// `999_000`, and negative numbers are capped symmetrically. The cap is a `u128` rather
+    /// than an `isize`, like [Options::max_magnitude], so that it can also cap `i128` and
+    /// `u128` numbers above the range of `isize`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::wide"))]
+    pub saturate_at: Option<u128>,
+    /// The marker written after the unit of numbers capped by [Options::saturate_at].
+    /// `+` by default.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub saturate_suffix: Option<&'a str>,
+    /// The number of abbreviation units that numbers are already scaled by, such as `1`
+    /// for numbers stored in thousands, or `-1` for numbers stored in thousandths. Numbers
+    /// at level `n` use the abbreviation unit at index `n + offset`, and fail when there is
+    /// no unit at that index in either direction. `0` by default.
+    pub base_level_offset: Option<i32>,
+    /// The fraction of the last kept decimal place at or above which the dropped digits
+    /// round away from zero, such as `0.6`. This overrides [Options::rounding_strategy]
+    /// and [Options::negative_rounding_strategy] when set.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::decimal"))]
+    pub round_threshold: Option<Decimal>,
+    /// Whether to write the unit before the mantissa, following the sign and
+    /// [Options::prefix], such as `k1.5`. `false` by default.
+    pub unit_before: bool,
+    /// Whether to never write a number larger than it is, by rounding toward zero and
+    /// never promoting a mantissa that rounds up to the next unit. This overrides
+    /// [Options::rounding_strategy], [Options::negative_rounding_strategy] and
+    /// [Options::round_threshold]. `false` by default.
+    pub no_unit_promotion: bool,
+    /// A unit that numbers are already in, such as `M` for numbers in millions. When set,
+    /// numbers are written at face value followed by this unit, without being scaled
+    /// down. This overrides [Options::notation] and [Options::fixed_unit].
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub assume_unit: Option<&'a str>,
+    /// Whether to cap the precision at the decimal places that the resolution of the
+    /// number supports, such as `3` for integers in thousands, so that neither rounding
+    /// nor trailing zeros show digits that the number does not have. `false` by default.
+    pub clamp_precision: bool,
+}
+
+impl<'a> Options<'a> {
+    /// Returns the options with [Options::rounding_strategy] and
+    /// [Options::negative_rounding_strategy] set for the given [Rounding].
+    pub const fn with_rounding(self, rounding: Rounding) -> Self {
+        let (strategy, negative_strategy) = match rounding {
+            Rounding::HalfEven => (RoundingStrategy::MidpointNearestEven, None),
+            Rounding::HalfUp => (RoundingStrategy::MidpointAwayFromZero, None),
+            Rounding::Up => (
+                RoundingStrategy::AwayFromZero,
+                Some(RoundingStrategy::ToZero),
+            ),
+            Rounding::Down => (
+                RoundingStrategy::ToZero,
+                Some(RoundingStrategy::AwayFromZero),
+            ),
+            Rounding::TowardZero => (RoundingStrategy::ToZero, None),
+        };
+
+        Options {
+            rounding_strategy: Some(strategy),
+            negative_rounding_strategy: negative_strategy,
+            ..self
+        }
+    }
+
+    /// Returns the options if their abbreviation units can be told apart, as
+    /// [parse_abbrev](crate::parse_abbrev) requires.
+    ///
+    /// Fails with [AbbrevError::EmptyUnit] if a unit above level `0` is empty, or with
+    /// [AbbrevError::DuplicateUnit] if a unit repeats that of a lower level.
+    pub fn validated(self) -> Result<Self, AbbrevError> {
+        let units = abbreviations(&self);
+
+        for (index, unit) in units.iter().enumerate().skip(1) {
+            let level = index as u32 + first_level(&self);
+
+            if unit.is_empty() {
+                return Err(AbbrevError::EmptyUnit { level });
+            }
+
+            if units[..index].contains(unit) {
+                return Err(AbbrevError::DuplicateUnit { level });
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the default options with the presets of the given [Locale] applied.
+    ///
+    /// The fields set by the locale can still be overridden afterwards, such as
+    /// `Options { precision: Some(2), ..Options::for_locale(Locale::DeDe) }`.
+    pub const fn for_locale(locale: Locale) -> Self {
+        Options::const_default().with_locale(locale)
+    }
+
+    /// Returns the options with the separators, grouping and units of the given [Locale]
+    /// set.
+    pub const fn with_locale(self, locale: Locale) -> Self {
+        match locale {
+            Locale::EnUs => Options {
+                decimal_separator: Some('.'),
+                group_separator: Some(','),
+                grouping: Grouping::Thousands,
+                ..self
+            },
+            Locale::DeDe => Options {
+                decimal_separator: Some(','),
+                group_separator: Some('.'),
+                grouping: Grouping::Thousands,
+                unit_separator: Some("\u{00A0}"),
+                ..self
+            },
+            Locale::FrFr => Options {
+                decimal_separator: Some(','),
+                group_separator: Some('\u{202F}'),
+                grouping: Grouping::Thousands,
+                unit_separator: Some("\u{00A0}"),
+                ..self
+            },
+            Locale::EnIn => Options {
+                decimal_separator: Some('.'),
+                group_separator: Some(','),
+                grouping: Grouping::Indian,
+                unit_scale: Some(INDIAN_UNIT_SCALE),
+                ..self
+            },
+        }
+    }
+
+    /// Returns the default options in const contexts, such as a shared
+    /// `const OPTIONS: Options = ...` policy. This equals [Options::default].
+    pub const fn const_default() -> Self {
+        Options {
+            precision: None,
+            min_precision: None,
+            abbreviations: None,
+            rounding_strategy: None,
+            small_units: None,
+            base: None,
+            step: None,
+            space: false,
+            long_words: false,
+            decimal_separator: None,
+            group_separator: None,
+            min_abbrev_value: None,
+            prefix: None,
+            suffix: None,
+            sign_position: SignPosition::BeforePrefix,
+            scientific_fallback: false,
+            width: None,
+            fill: None,
+            align: Align::Right,
+            notation: NotationMode::Compact,
+            negative_rounding_strategy: None,
+            unit_separator: None,
+            negative_style: NegativeStyle::Minus,
+            superscript_exponent: false,
+            significant_digits: None,
+            keep_trailing_zeros: false,
+            zero_text: None,
+            long_words_plural: None,
+            unit_scale: None,
+            scale: NumberScale::Short,
+            fixed_unit: None,
+            max_width: None,
+            hide_zero_exponent: false,
+            force_sign: false,
+            force_sign_zero: false,
+            max_precision: None,
+            clamp_to_max_unit: false,
+            precision_by_level: None,
+            long_bit_rate: false,
+            always_decimal: false,
+            grouping: Grouping::Thousands,
+            group_fraction: false,
+            max_magnitude: None,
+            scale_table: None,
+            nice_numbers: false,
+            mantissa_width: None,
+            normalization: Normalization::Trim,
+            saturate_at: None,
+            saturate_suffix: None,
+            base_level_offset: None,
+            round_threshold: None,
+            unit_before: false,
+            no_unit_promotion: false,
+            assume_unit: None,
+            clamp_precision: false,
+        }
+    }
+}
+
+/// The position of the negative sign relative to the prefix of a number.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignPosition {
+    /// The sign is written before the prefix, such as `-$5k`.
+    #[default]
+    BeforePrefix,
+    /// The sign is written after the prefix, such as `$-5k`.
+    AfterPrefix,
+    /// The sign is written after the unit and before [Options::suffix], such as
+    /// `$5k- USD`, as in some accounting reports.
+    Suffix,
+}
+
+/// The alignment of a result that is padded to a width.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Align {
+    /// The result is padded on the left, such as `  1.5k`.
+    #[default]
+    Right,
+    /// The result is padded on the right, such as `1.5k  `.
+    Left,
+}
+
+/// The notation of an abbreviated number, as in the `notation` option of JavaScript's
+/// `Intl.NumberFormat`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NotationMode {
+    /// The number is abbreviated with a unit, such as `1.5M`.
+    #[default]
+    Compact,
+    /// The number is written in full without a unit, such as `1500000`.
+    Standard,
+    /// The number is written with a power of ten, such as `1.5e6`.
+    Scientific,
+    /// The number is written with a power of ten that is a multiple of three, such as
+    /// `15e3`.
+    Engineering,
+}
+
+/// The style of negative numbers.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NegativeStyle {
+    /// The number is written with a minus sign, such as `-$1.5k`.
+    #[default]
+    Minus,
+    /// The number is wrapped in parentheses along with its prefix and suffix, such as
+    /// `($1.5k)`, as in accounting.
+    Parentheses,
+}
+
+/// The naming scale of full-word units for large numbers.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NumberScale {
+    /// The short scale of American and modern British English, where `10^9` is a
+    /// billion.
+    #[default]
+    Short,
+    /// The long scale of continental Europe and traditional British English, where `10^9`
+    /// is a milliard and `10^12` is a billion.
+    Long,
+}
+
+/// The handling of trailing zeros in the fractional part of a result.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Normalization {
+    /// Trailing zeros are trimmed, such as `1M` and `1.5M` at a precision of two.
+    #[default]
+    Trim,
+    /// Exactly [Options::precision] decimal places are written, such as `1.00M` and
+    /// `1.50M` at a precision of two.
+    Fixed,
+}
+
+/// The grouping of the integer digits of a number.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Grouping {
+    /// The digits are grouped by thousands, such as `1,234,567`.
+    #[default]
+    Thousands,
+    /// The last three digits are grouped, followed by groups of two as in India, such as
+    /// `12,34,567`.
+    Indian,
+}
+
+/// A locale with preset separators, grouping and units, applied with
+/// [Options::for_locale].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Locale {
+    /// American English, such as `1,234.5k`.
+    EnUs,
+    /// German, with a non-breaking space before the unit, such as `1.234,5 k`.
+    DeDe,
+    /// French, with narrow non-breaking spaces between groups and a non-breaking space
+    /// before the unit, such as `1 234,5 k`.
+    FrFr,
+    /// Indian English, with lakh and crore units and Indian grouping, such as
+    /// `1,23,456.8Cr`.
+    EnIn,
+}
+
+impl Locale {
+    /// The supported locales.
+    const ALL: [Locale; 4] = [Locale::EnUs, Locale::DeDe, Locale::FrFr, Locale::EnIn];
+
+    /// Returns the locale for the given language tag such as `de-DE`, ignoring case and
+    /// accepting an underscore in place of the hyphen.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        Locale::ALL.into_iter().find(|locale| {
+            let expected = locale.tag();
+
+            tag.len() == expected.len()
+                && tag.bytes().zip(expected.bytes()).all(|(char, expected)| {
+                    match (char, expected) {
+                        (b'_', b'-') => true,
+                        _ => char.eq_ignore_ascii_case(&expected),
+                    }
+                })
+        })
+    }
+
+    /// Returns the language tag of the locale, such as `de-DE`.
+    pub const fn tag(self) -> &'static str {
+        match self {
+            Locale::EnUs => "en-US",
+            Locale::DeDe => "de-DE",
+            Locale::FrFr => "fr-FR",
+            Locale::EnIn => "en-IN",
+        }
+    }
+}
+
+/// The Indian thousand, lakh and crore units used with [Locale::EnIn].
+const INDIAN_UNIT_SCALE: &[(u128, &str)] = &[(1_000, "K"), (100_000, "L"), (10_000_000, "Cr")];
+
+/// A common rounding mode, as a simpler alternative to [RoundingStrategy] that is applied
+/// with [Options::with_rounding].
+///
+/// Unlike a [RoundingStrategy], which applies to the absolute value of a number, these
+/// modes take the sign into account.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rounding {
+    /// Midpoints are rounded to the nearest even number, such as `2.5k` to `2k`. This is
+    /// the default, also known as banker's rounding.
+    #[default]
+    HalfEven,
+    /// Midpoints are rounded away from zero, such as `2.5k` to `3k` and `-2.5k` to `-3k`.
+    HalfUp,
+    /// Numbers are rounded towards positive infinity, such as `2.1k` to `3k` and `-2.9k`
+    /// to `-2k`.
+    Up,
+    /// Numbers are rounded towards negative infinity, such as `2.9k` to `2k` and `-2.1k`
+    /// to `-3k`.
+    Down,
+    /// Numbers are rounded towards zero, such as `2.9k` to `2k` and `-2.9k` to `-2k`.
+    TowardZero,
+}
@@ -0,0 +1,234 @@
+use crate::{abbrev, Integer, Options};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Options that own all of their text and lists, for callers that cannot keep borrowed
+/// units alive, such as bindings that receive them from JavaScript.
+///
+/// Each owned field overrides the matching borrowed field of [OwnedOptions::options]
+/// when set. The remaining fields are read from [OwnedOptions::options] as is.
+///
+/// With the `serde` feature enabled, owned options are serialized as the [Options] they
+/// borrow, and deserialized with their text and lists owned. Options serialized with
+/// borrowed units can thus be deserialized as owned options without losing them.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{abbrev_owned, OwnedOptions};
+///
+/// let units: Vec<String> = vec!["".into(), " K".into(), " M".into()];
+/// let options = OwnedOptions {
+///     abbreviations: Some(units),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(abbrev_owned(1_500, &options), Some("1.5 K".to_string()));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct OwnedOptions {
+    /// The options without any borrowed text or lists.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub options: Options<'static>,
+    /// The owned [Options::abbreviations].
+    pub abbreviations: Option<Vec<String>>,
+    /// The owned [Options::small_units].
+    pub small_units: Option<Vec<String>>,
+    /// The owned [Options::long_words_plural].
+    pub long_words_plural: Option<Vec<String>>,
+    /// The owned [Options::unit_scale].
+    pub unit_scale: Option<Vec<(u128, String)>>,
+    /// The owned [Options::precision_by_level].
+    pub precision_by_level: Option<Vec<u32>>,
+    /// The owned [Options::prefix].
+    pub prefix: Option<String>,
+    /// The owned [Options::suffix].
+    pub suffix: Option<String>,
+    /// The owned [Options::unit_separator].
+    pub unit_separator: Option<String>,
+    /// The owned [Options::zero_text].
+    pub zero_text: Option<String>,
+    /// The owned [Options::fixed_unit].
+    pub fixed_unit: Option<String>,
+    /// The owned [Options::saturate_suffix].
+    pub saturate_suffix: Option<String>,
+    /// The owned [Options::assume_unit].
+    pub assume_unit: Option<String>,
+}
+
+impl OwnedOptions {
+    /// Creates owned options from options without any borrowed text or lists.
+    pub fn new(options: Options<'static>) -> Self {
+        OwnedOptions {
+            options,
+            ..Default::default()
+        }
+    }
+
+    /// Calls the given function with [Options] borrowing from these owned options.
+    pub fn with_options<R>(&self, f: impl FnOnce(Options) -> R) -> R {
+        let abbreviations = self.abbreviations.as_deref().map(borrow_all);
+        let small_units = self.small_units.as_deref().map(borrow_all);
+        let long_words_plural = self.long_words_plural.as_deref().map(borrow_all);
+        let unit_scale: Option<Vec<(u128, &str)>> = self.unit_scale.as_ref().map(|scale| {
+            scale
+                .iter()
+                .map(|(threshold, unit)| (*threshold, unit.as_str()))
+                .collect()
+        });
+
+        f(Options {
+            abbreviations: abbreviations.as_deref().or(self.options.abbreviations),
+            small_units: small_units.as_deref().or(self.options.small_units),
+            long_words_plural: long_words_plural
+                .as_deref()
+                .or(self.options.long_words_plural),
+            unit_scale: unit_scale.as_deref().or(self.options.unit_scale),
+            precision_by_level: self
+                .precision_by_level
+                .as_deref()
+                .or(self.options.precision_by_level),
+            prefix: self.prefix.as_deref().or(self.options.prefix),
+            suffix: self.suffix.as_deref().or(self.options.suffix),
+            unit_separator: self
+                .unit_separator
+                .as_deref()
+                .or(self.options.unit_separator),
+            zero_text: self.zero_text.as_deref().or(self.options.zero_text),
+            fixed_unit: self.fixed_unit.as_deref().or(self.options.fixed_unit),
+            saturate_suffix: self
+                .saturate_suffix
+                .as_deref()
+                .or(self.options.saturate_suffix),
+            assume_unit: self.assume_unit.as_deref().or(self.options.assume_unit),
+            ..self.options
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for OwnedOptions {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.with_options(|options| options.serialize(serializer))
+    }
+}
+
+impl From<Options<'static>> for OwnedOptions {
+    fn from(options: Options<'static>) -> Self {
+        OwnedOptions::new(options)
+    }
+}
+
+/// Abbreviates the given integer into a human-friendly format according to the given
+/// owned options.
+///
+/// This behaves exactly like [abbrev](crate::abbrev) with the [Options] borrowed from
+/// the owned options.
+///
+/// # Arguments
+///
+/// * `number` - The integer to be abbreviated.
+/// * `options` - The [OwnedOptions] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated form of the number.
+/// Returns `None` if the number is out of bounds or cannot be abbreviated using the
+/// provided abbreviations.
+pub fn abbrev_owned(number: impl Integer, options: &OwnedOptions) -> Option<String> {
+    options.with_options(|options| abbrev(number, Some(options)))
+}
+
+/// Borrows each of the given strings.
+fn borrow_all(values: &[String]) -> Vec<&str> {
+    values.iter().map(String::as_str).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NotationMode;
+    use alloc::string::ToString;
+
+    #[test]
+    fn can_abbreviate_using_owned_options() {
+        let units: Vec<String> = ["", "_c1", "_c2"]
+            .iter()
+            .map(|unit| unit.to_string())
+            .collect();
+        let options = OwnedOptions {
+            abbreviations: Some(units),
+            prefix: Some("$".to_string()),
+            precision_by_level: Some(vec![0, 0, 2]),
+            ..OwnedOptions::new(Options {
+                space: true,
+                suffix: Some(" USD"),
+                ..Default::default()
+            })
+        };
+        let fixtures: Vec<(i64, Option<&str>)> = vec![
+            (150, Some("$150 USD")),
+            (1_500, Some("$2 _c1 USD")),
+            (-1_234_567, Some("-$1.23 _c2 USD")),
+            (1_500_000_000, None),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            let result = abbrev_owned(*case, &options);
+            assert_eq!(result, expected.map(|value| value.to_string()));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_using_owned_unit_scale() {
+        let options = OwnedOptions {
+            unit_scale: Some(vec![(1_000, "K".to_string()), (100_000, "L".to_string())]),
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_owned(250_000, &options), Some("2.5L".to_string()));
+
+        let options = OwnedOptions::from(Options {
+            notation: NotationMode::Scientific,
+            ..Default::default()
+        });
+
+        assert_eq!(abbrev_owned(1_500_u16, &options), Some("1.5e3".to_string()));
+        assert_eq!(
+            options.with_options(|options| options.notation),
+            NotationMode::Scientific
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_using_owned_saturate_suffix() {
+        let options = OwnedOptions {
+            saturate_suffix: Some(" or more".to_string()),
+            ..OwnedOptions::new(Options {
+                saturate_at: Some(999_000),
+                ..Default::default()
+            })
+        };
+
+        assert_eq!(
+            abbrev_owned(5_000_000, &options),
+            Some("999k or more".to_string())
+        );
+        assert_eq!(abbrev_owned(1_500, &options), Some("1.5k".to_string()));
+    }
+
+    #[test]
+    fn can_abbreviate_using_owned_assume_unit() {
+        let options = OwnedOptions {
+            assume_unit: Some("Mio".to_string()),
+            ..OwnedOptions::new(Options {
+                space: true,
+                ..Default::default()
+            })
+        };
+
+        assert_eq!(abbrev_owned(25, &options), Some("25 Mio".to_string()));
+    }
+}
@@ -0,0 +1,472 @@
+use crate::{abbreviations, base, first_level, plurals, Options};
+use alloc::format;
+use alloc::string::String;
+
+/// Parses an abbreviated number back into its approximate numeric value.
+///
+/// This is the inverse of [abbrev_num](crate::abbrev_num), using the same abbreviation
+/// units from the given options. Leading and trailing whitespace and an optional `+` or
+/// `-` sign are tolerated, even after the unit, as are parentheses around negative
+/// numbers without a sign of their own, as well as whitespace or
+/// [Options::unit_separator] between the number and its unit. Numbers in scientific
+/// notation such as `1.5e6` are parsed as well, while a bare `E` is still the exa unit.
+/// Units for values below one are parsed from [Options::small_units] when set, where
+/// units are case-sensitive, so `m` is milli and `M` is mega.
+///
+/// # Arguments
+///
+/// * `value` - The abbreviated number to be parsed.
+/// * `options` - An optional parameter specifying the [Options] used for abbreviation.
+///
+/// # Returns
+///
+/// `Some(value)`, the numeric value of the abbreviated number. Returns `None` if the
+/// mantissa is malformed or the unit is unknown.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::parse_abbrev;
+///
+/// assert_eq!(parse_abbrev("1.5k", None), Some(1_500.0));
+/// assert_eq!(parse_abbrev("-2.3M", None), Some(-2_300_000.0));
+/// ```
+pub fn parse_abbrev(value: &str, options: Option<Options>) -> Option<f64> {
+    let options = options.unwrap_or_default();
+    let value = value.trim();
+    let (parentheses, value) = match value
+        .strip_prefix('(')
+        .and_then(|value| value.strip_suffix(')'))
+    {
+        Some(value) => (true, value),
+        None => (false, value),
+    };
+    let value = options
+        .suffix
+        .and_then(|suffix| value.strip_suffix(suffix))
+        .unwrap_or(value);
+    let (leading, value) = strip_sign(value);
+    let (sign, value) = match options.prefix.and_then(|prefix| value.strip_prefix(prefix)) {
+        // A sign is also accepted after the prefix, but only one of them.
+        Some(value) => match (leading, strip_sign(value)) {
+            (Some(_), (Some(_), _)) => return None,
+            (leading, (after_prefix, value)) => (leading.or(after_prefix), value),
+        },
+        None => (leading, value),
+    };
+    // A trailing sign, as written with `SignPosition::Suffix`, is only accepted alone.
+    let (sign, value) = match (sign, strip_trailing_sign(value)) {
+        (Some(_), (Some(_), _)) => return None,
+        (sign, (trailing, value)) => (sign.or(trailing), value),
+    };
+
+    let sign = match (parentheses, sign) {
+        (true, Some(_)) => return None,
+        (true, None) => "-",
+        (false, sign) => sign.map_or("", Sign::as_str),
+    };
+
+    // The separators are only replaced within the mantissa, as units may contain them,
+    // such as `Mio.` with a `.` group separator.
+    let separators = [options.group_separator, options.decimal_separator];
+    let split = value
+        .find(|char: char| {
+            !char.is_ascii_digit() && char != '.' && !separators.contains(&Some(char))
+        })
+        .unwrap_or(value.len());
+    let (mantissa, written_unit) = value.split_at(split);
+    let mantissa: String = mantissa
+        .chars()
+        .filter(|char| Some(*char) != options.group_separator)
+        .map(|char| {
+            if Some(char) == options.decimal_separator {
+                '.'
+            } else {
+                char
+            }
+        })
+        .collect();
+    let unit = options
+        .unit_separator
+        .and_then(|separator| written_unit.strip_prefix(separator))
+        .unwrap_or(written_unit)
+        .trim_start();
+
+    if !mantissa.contains(|char: char| char.is_ascii_digit()) || mantissa.matches('.').count() > 1 {
+        return None;
+    }
+
+    let position = |units: &[&str]| units.iter().position(|abbreviation| *abbreviation == unit);
+    let (level, exponent) = if let Some(index) =
+        position(abbreviations(&options)).or_else(|| position(plurals(&options)?))
+    {
+        ((index as u32 + first_level(&options)) as i64, 0)
+    } else if unit.is_empty() {
+        (0, 0)
+    } else if let Some(level) = options.small_units.and_then(position) {
+        (-(level as i64 + 1), 0)
+    } else {
+        // Unlike a unit, the exponent directly follows the mantissa.
+        (0, parse_exponent(written_unit)?)
+    };
+
+    let base = base(&options).ok()?;
+    let digits = base.ilog10();
+
+    let result: f64 = if 10_u128.pow(digits) == base {
+        // Parsing the scientific notation keeps the result correctly rounded.
+        format!("{sign}{mantissa}e{}", level * digits as i64 + exponent)
+            .parse()
+            .ok()?
+    } else {
+        let mantissa: f64 = format!("{sign}{mantissa}e{exponent}").parse().ok()?;
+        (0..level.unsigned_abs()).fold(mantissa, |value, _| {
+            if level < 0 {
+                value / base as f64
+            } else {
+                value * base as f64
+            }
+        })
+    };
+
+    Some(result).filter(|result| result.is_finite())
+}
+
+/// Parses an exponent of scientific notation such as `e6` or `E-3`, without whitespace
+/// around the marker.
+fn parse_exponent(value: &str) -> Option<i64> {
+    value.strip_prefix(['e', 'E'])?.parse().ok()
+}
+
+/// An explicit sign of a parsed number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Sign {
+    Plus,
+    Minus,
+}
+
+impl Sign {
+    /// Returns the sign as written before a parsed mantissa.
+    fn as_str(self) -> &'static str {
+        match self {
+            Sign::Plus => "",
+            Sign::Minus => "-",
+        }
+    }
+
+    /// Returns the sign written as the given character, if any.
+    fn from_char(char: char) -> Option<Sign> {
+        match char {
+            '+' => Some(Sign::Plus),
+            '-' => Some(Sign::Minus),
+            _ => None,
+        }
+    }
+}
+
+/// Splits an optional leading sign from the value.
+fn strip_sign(value: &str) -> (Option<Sign>, &str) {
+    match value.chars().next().and_then(Sign::from_char) {
+        Some(sign) => (Some(sign), &value[1..]),
+        None => (None, value),
+    }
+}
+
+/// Splits an optional trailing sign from the value, as written with
+/// [SignPosition::Suffix](crate::SignPosition::Suffix).
+fn strip_trailing_sign(value: &str) -> (Option<Sign>, &str) {
+    match value.chars().next_back().and_then(Sign::from_char) {
+        Some(sign) => (Some(sign), &value[..value.len() - 1]),
+        None => (None, value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{abbrev_float, SMALL_ABBREVIATIONS};
+
+    #[test]
+    fn can_parse_abbreviated_numbers() {
+        let fixtures: Vec<(&str, f64)> = vec![
+            ("0", 0.0),
+            ("999", 999.0),
+            ("1.5k", 1_500.0),
+            ("-2.3M", -2_300_000.0),
+            ("+4B", 4_000_000_000.0),
+            ("  1.2k \n", 1_200.0),
+            ("1.5 k", 1_500.0),
+            (".5k", 500.0),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            assert_eq!(parse_abbrev(case, None), Some(*expected));
+        });
+    }
+
+    #[test]
+    fn can_parse_using_custom_units() {
+        let units = ["_c0", "_c1", "_c2"];
+        let options = Options {
+            abbreviations: Some(&units),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_abbrev("10_c0", Some(options)), Some(10.0));
+        assert_eq!(parse_abbrev("1.5_c2", Some(options)), Some(1_500_000.0));
+        assert_eq!(parse_abbrev("1.5k", Some(options)), None);
+    }
+
+    #[test]
+    fn can_parse_using_custom_base() {
+        let options = Options {
+            base: Some(1024),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_abbrev("1.5k", Some(options)), Some(1_536.0));
+        assert_eq!(parse_abbrev("-2M", Some(options)), Some(-2_097_152.0));
+
+        let options = Options {
+            base: Some(100),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_abbrev("2.5M", Some(options)), Some(25_000.0));
+
+        let options = Options {
+            step: Some(6),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_abbrev("2.5k", Some(options)), Some(2_500_000.0));
+    }
+
+    #[test]
+    fn can_parse_using_custom_decimal_separator() {
+        let options = Options {
+            decimal_separator: Some(','),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_abbrev("1,5k", Some(options)), Some(1_500.0));
+        assert_eq!(parse_abbrev("-999", Some(options)), Some(-999.0));
+
+        let options = Options {
+            decimal_separator: Some(','),
+            group_separator: Some('.'),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_abbrev("1.234,5k", Some(options)), Some(1_234_500.0));
+
+        let options = Options {
+            abbreviations: Some(&["", "Tsd.", "Mio."]),
+            ..options
+        };
+
+        assert_eq!(
+            parse_abbrev("1.234,5 Tsd.", Some(options)),
+            Some(1_234_500.0)
+        );
+        assert_eq!(parse_abbrev("2 Mio", Some(options)), None);
+    }
+
+    #[test]
+    fn can_parse_with_prefix_and_suffix() {
+        let options = Options {
+            prefix: Some("$"),
+            suffix: Some(" USD"),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_abbrev("$1.5M USD", Some(options)), Some(1_500_000.0));
+        assert_eq!(parse_abbrev("-$5k USD", Some(options)), Some(-5_000.0));
+        assert_eq!(parse_abbrev("$-5k", Some(options)), Some(-5_000.0));
+        assert_eq!(parse_abbrev("$--5k", Some(options)), None);
+        assert_eq!(parse_abbrev("$5k- USD", Some(options)), Some(-5_000.0));
+        assert_eq!(parse_abbrev("-$5k-", Some(options)), None);
+        assert_eq!(parse_abbrev("+$-5k", Some(options)), None);
+        assert_eq!(parse_abbrev("-$+5k", Some(options)), None);
+    }
+
+    #[test]
+    fn cannot_parse_repeated_signs() {
+        let fixtures = ["+-2.3M", "-+2.3M", "--1k", "++1k"];
+
+        fixtures.iter().for_each(|case| {
+            assert_eq!(parse_abbrev(case, None), None, "{case}");
+        });
+        assert_eq!(parse_abbrev("+2.3M", None), Some(2_300_000.0));
+    }
+
+    #[test]
+    fn cannot_parse_leading_and_trailing_signs() {
+        let fixtures = ["+5k-", "-5k+", "-5k-", "+5k+"];
+
+        fixtures.iter().for_each(|case| {
+            assert_eq!(parse_abbrev(case, None), None, "{case}");
+        });
+        assert_eq!(parse_abbrev("5k+", None), Some(5_000.0));
+        assert_eq!(parse_abbrev("5k-", None), Some(-5_000.0));
+    }
+
+    #[test]
+    fn cannot_parse_malformed_numbers() {
+        let fixtures = ["", "k", "-", "1.2.3k", "1.5X", "--1", "1k5"];
+
+        fixtures.iter().for_each(|case| {
+            assert_eq!(parse_abbrev(case, None), None);
+        });
+    }
+
+    #[test]
+    fn can_parse_with_unit_separator() {
+        let options = Options {
+            unit_separator: Some("_"),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_abbrev("1.5_k", Some(options)), Some(1_500.0));
+        assert_eq!(parse_abbrev("1.5k", Some(options)), Some(1_500.0));
+        assert_eq!(parse_abbrev("1.5\u{00A0}M", None), Some(1_500_000.0));
+        assert_eq!(parse_abbrev("1.5_e3", Some(options)), None);
+    }
+
+    #[test]
+    fn can_parse_parenthesized_negatives() {
+        let options = Options {
+            prefix: Some("$"),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_abbrev("(1.5k)", None), Some(-1_500.0));
+        assert_eq!(parse_abbrev("($1.5k)", Some(options)), Some(-1_500.0));
+        assert_eq!(parse_abbrev("(-1.5k)", None), None);
+        assert_eq!(parse_abbrev("(+1.5k)", None), None);
+        assert_eq!(parse_abbrev("(1.5k+)", None), None);
+        assert_eq!(parse_abbrev("($+1.5k)", Some(options)), None);
+        assert_eq!(parse_abbrev("(1.5k", None), None);
+    }
+
+    #[test]
+    fn can_parse_plural_units() {
+        let options = Options {
+            abbreviations: Some(&["", "grand", "mega"]),
+            long_words_plural: Some(&["", "grands"]),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_abbrev("1 grand", Some(options)), Some(1_000.0));
+        assert_eq!(parse_abbrev("2.5 grands", Some(options)), Some(2_500.0));
+        assert_eq!(parse_abbrev("3 mega", Some(options)), Some(3_000_000.0));
+        assert_eq!(parse_abbrev("3 megas", Some(options)), None);
+    }
+
+    #[test]
+    fn can_parse_using_base_level_offset() {
+        let options = Options {
+            base_level_offset: Some(1),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_abbrev("5k", Some(options)), Some(5.0));
+        assert_eq!(parse_abbrev("1.5M", Some(options)), Some(1_500.0));
+    }
+
+    #[test]
+    fn can_parse_scientific_notation() {
+        let fixtures: Vec<(&str, f64)> = vec![
+            ("2.5e3", 2_500.0),
+            ("1.5e6", 1_500_000.0),
+            ("-1.5E+6", -1_500_000.0),
+            ("2.5e-3", 0.0025),
+            ("2.5E", 2.5e18),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            assert_eq!(parse_abbrev(case, None), Some(*expected));
+        });
+
+        let options = Options {
+            base: Some(1024),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_abbrev("1.5e3", Some(options)), Some(1_500.0));
+
+        let fixtures = [
+            "1.5e", "1.5e+", "1.5e3k", "1.5e 3", "1.5 e3", "1.5  e3", "1.5\te3", "1e999",
+        ];
+
+        fixtures.iter().for_each(|case| {
+            assert_eq!(parse_abbrev(case, None), None);
+        });
+    }
+
+    #[test]
+    fn can_parse_small_units() {
+        let options = Options {
+            small_units: Some(&SMALL_ABBREVIATIONS[..]),
+            ..Default::default()
+        };
+        let fixtures: Vec<(&str, f64)> = vec![
+            ("3.4m", 0.0034),
+            ("500µ", 0.0005),
+            ("-1.5n", -0.0000000015),
+            ("2p", 0.000000000002),
+            ("3.4M", 3_400_000.0),
+            ("3.4", 3.4),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            assert_eq!(parse_abbrev(case, Some(options)), Some(*expected));
+        });
+
+        assert_eq!(parse_abbrev("3.4m", None), None);
+
+        let options = Options {
+            base: Some(1024),
+            ..options
+        };
+
+        assert_eq!(parse_abbrev("512m", Some(options)), Some(0.5));
+    }
+
+    #[test]
+    fn can_parse_small_and_large_units_back() {
+        let options = Options {
+            small_units: Some(&SMALL_ABBREVIATIONS[..]),
+            precision: Some(3),
+            ..Default::default()
+        };
+        let fixtures: Vec<f64> = vec![
+            0.0034,
+            -0.000_512,
+            0.000_000_123_4,
+            4.5e-12,
+            0.5,
+            7.0,
+            1_234.0,
+            -98_765_000.0,
+        ];
+
+        fixtures.iter().for_each(|case| {
+            let result = abbrev_float(*case, Some(options)).unwrap();
+            let parsed = parse_abbrev(&result, Some(options)).unwrap();
+            let error = ((parsed - case) / case).abs();
+            assert!(error < 5e-4, "{case} became {result} and {parsed}");
+        });
+    }
+
+    #[test]
+    fn can_parse_using_negative_base_level_offset() {
+        let options = Options {
+            base_level_offset: Some(-1),
+            ..Default::default()
+        };
+
+        assert_eq!(parse_abbrev("1.5", Some(options)), Some(1_500.0));
+        assert_eq!(parse_abbrev("2k", Some(options)), Some(2_000_000.0));
+    }
+}
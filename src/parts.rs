@@ -0,0 +1,314 @@
+use crate::format::Mantissa;
+use crate::{fitted_parts, integer_parts, Integer, Options};
+use alloc::string::String;
+use core::fmt;
+use rust_decimal::prelude::ToPrimitive;
+
+/// The sign of an abbreviated number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Sign {
+    /// The number is below zero.
+    Negative,
+    /// The number is zero, or rounds to zero.
+    Zero,
+    /// The number is above zero.
+    Positive,
+}
+
+/// The direction of a change, as returned by [abbrev_delta].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Trend {
+    /// The change is above zero.
+    Up,
+    /// The change is below zero.
+    Down,
+    /// The change is zero, or rounds to zero.
+    Flat,
+}
+
+/// The separate parts of an abbreviated number, as returned by [abbrev_parts].
+///
+/// Displaying the parts joins them into exactly what [abbrev_num](crate::abbrev_num)
+/// returns for the same inputs, apart from [Options::prefix], [Options::suffix],
+/// [Options::saturate_suffix] and the signs of [Options::negative_style] and
+/// [Options::force_sign].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbbrevParts<'a> {
+    /// The sign of the number.
+    pub sign: Sign,
+    /// The numeric part of the number without its sign, such as `1.5`.
+    pub mantissa: String,
+    /// The separator between the mantissa and the unit, such as a space.
+    pub separator: &'a str,
+    /// The abbreviation unit, such as `k`.
+    pub unit: &'a str,
+}
+
+impl fmt::Display for AbbrevParts<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.sign == Sign::Negative {
+            f.write_str("-")?;
+        }
+
+        write!(f, "{}{}{}", self.mantissa, self.separator, self.unit)
+    }
+}
+
+/// Abbreviates the given number into its separate parts according to specified options.
+///
+/// This is useful to style the mantissa and the unit differently without parsing the
+/// joined string of [abbrev_num](crate::abbrev_num).
+///
+/// # Arguments
+///
+/// * `number` - The integer to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some(parts)`, the [AbbrevParts] of the abbreviated number. Returns `None` if the
+/// number is out of bounds or cannot be abbreviated using the provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{abbrev_parts, Sign};
+///
+/// let parts = abbrev_parts(-1_500, None).unwrap();
+///
+/// assert_eq!(parts.sign, Sign::Negative);
+/// assert_eq!(parts.mantissa, "1.5");
+/// assert_eq!(parts.unit, "k");
+/// ```
+pub fn abbrev_parts<'a>(number: isize, options: Option<Options<'a>>) -> Option<AbbrevParts<'a>> {
+    let options = options.unwrap_or_default();
+    let (options, parts) =
+        fitted_parts(&options, |options| integer_parts(number, options, "")).ok()?;
+    let mut mantissa = String::new();
+    // Writing into a string never fails.
+    let _ = parts.write_mantissa(&mut mantissa, &options);
+
+    Some(AbbrevParts {
+        sign: if parts.is_negative() {
+            Sign::Negative
+        } else if parts.mantissa == Mantissa::Zero {
+            Sign::Zero
+        } else {
+            Sign::Positive
+        },
+        mantissa,
+        separator: parts.separator(&options),
+        unit: parts.unit,
+    })
+}
+
+/// Abbreviates the given number into its rounded mantissa as a float, along with its
+/// abbreviation unit.
+///
+/// The mantissa is rounded exactly like what [abbrev](crate::abbrev) writes for the same
+/// inputs, such as for animating it before appending the unit. In scientific notation,
+/// the mantissa is returned without its exponent.
+///
+/// # Arguments
+///
+/// * `number` - The integer to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some((mantissa, unit))`, the signed mantissa and the unit of the abbreviated number.
+/// Returns `None` if the number is out of bounds or cannot be abbreviated using the
+/// provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::abbrev_mantissa;
+///
+/// assert_eq!(abbrev_mantissa(1_500_000, None), Some((1.5, "M")));
+/// assert_eq!(abbrev_mantissa(-999, None), Some((-999.0, "")));
+/// ```
+pub fn abbrev_mantissa<'a>(
+    number: impl Integer,
+    options: Option<Options<'a>>,
+) -> Option<(f64, &'a str)> {
+    let options = options.unwrap_or_default();
+    let (_, parts) = fitted_parts(&options, |options| integer_parts(number, options, "")).ok()?;
+    let mantissa = match parts.mantissa {
+        Mantissa::Zero => 0.0,
+        Mantissa::Integer(value) => value as f64,
+        Mantissa::Decimal(value) => value.to_f64()?,
+    };
+    let mantissa = if parts.is_negative() {
+        -mantissa
+    } else {
+        mantissa
+    };
+
+    Some((mantissa, parts.unit))
+}
+
+/// Abbreviates the given change with a forced sign, along with its [Trend].
+///
+/// This sets [Options::force_sign], so that increases are written with a `+` sign, and
+/// classifies the change by the sign of its result, so that a change written as `0` is
+/// always [Trend::Flat].
+///
+/// # Arguments
+///
+/// * `number` - The change to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Returns
+///
+/// `Some((value, trend))`, a string representation of the abbreviated change and its
+/// [Trend]. Returns `None` if the number is out of bounds or cannot be abbreviated using
+/// the provided abbreviations.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{abbrev_delta, Trend};
+///
+/// assert_eq!(abbrev_delta(1_500, None), Some(("+1.5k".to_string(), Trend::Up)));
+/// assert_eq!(abbrev_delta(-300, None), Some(("-300".to_string(), Trend::Down)));
+/// assert_eq!(abbrev_delta(0, None), Some(("0".to_string(), Trend::Flat)));
+/// ```
+pub fn abbrev_delta(number: impl Integer, options: Option<Options>) -> Option<(String, Trend)> {
+    let options = Options {
+        force_sign: true,
+        ..options.unwrap_or_default()
+    };
+    let (options, parts) =
+        fitted_parts(&options, |options| integer_parts(number, options, "")).ok()?;
+    let trend = if parts.mantissa == Mantissa::Zero {
+        Trend::Flat
+    } else if parts.is_negative() {
+        Trend::Down
+    } else {
+        Trend::Up
+    };
+
+    Some((parts.to_string(&options), trend))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abbrev_num;
+
+    #[test]
+    fn can_abbreviate_into_parts() {
+        let parts = abbrev_parts(-1_500, None);
+
+        assert_eq!(
+            parts,
+            Some(AbbrevParts {
+                sign: Sign::Negative,
+                mantissa: "1.5".to_string(),
+                separator: "",
+                unit: "k",
+            })
+        );
+
+        assert_eq!(
+            abbrev_parts(0, None).map(|parts| parts.sign),
+            Some(Sign::Zero)
+        );
+        assert_eq!(
+            abbrev_parts(150, None).map(|parts| parts.sign),
+            Some(Sign::Positive)
+        );
+    }
+
+    #[test]
+    fn can_join_parts() {
+        let options = Options {
+            precision: Some(2),
+            space: true,
+            group_separator: Some(','),
+            ..Default::default()
+        };
+        let fixtures: Vec<isize> = vec![0, 150, -1_500, 1_234_567, isize::MIN];
+
+        fixtures.iter().for_each(|case| {
+            let result = abbrev_parts(*case, Some(options)).map(|parts| parts.to_string());
+            assert_eq!(result, abbrev_num(*case, Some(options)));
+        });
+    }
+
+    #[test]
+    fn can_abbreviate_deltas() {
+        let fixtures: Vec<(i64, &str, Trend)> = vec![
+            (0, "0", Trend::Flat),
+            (1_500, "+1.5k", Trend::Up),
+            (-300, "-300", Trend::Down),
+            (-2_345_678, "-2.3M", Trend::Down),
+            (i64::MAX, "+9.2E", Trend::Up),
+        ];
+
+        fixtures.iter().for_each(|(case, expected, trend)| {
+            let result = abbrev_delta(*case, None);
+            assert_eq!(result, Some((expected.to_string(), *trend)));
+        });
+
+        let options = Options {
+            zero_text: Some("–"),
+            force_sign_zero: true,
+            suffix: Some("%"),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            abbrev_delta(0_u8, Some(options)),
+            Some(("–".to_string(), Trend::Flat))
+        );
+        assert_eq!(
+            abbrev_delta(42_u8, Some(options)),
+            Some(("+42%".to_string(), Trend::Up))
+        );
+        assert_eq!(
+            abbrev_delta(
+                1,
+                Some(Options {
+                    abbreviations: Some(&[]),
+                    ..Default::default()
+                })
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn can_abbreviate_into_mantissa() {
+        let options = Options {
+            precision: Some(2),
+            ..Default::default()
+        };
+        let fixtures: Vec<(i64, Options, f64, &str)> = vec![
+            (0, Options::default(), 0.0, ""),
+            (150, Options::default(), 150.0, ""),
+            (1_500_000, Options::default(), 1.5, "M"),
+            (-1_250, Options::default(), -1.2, "k"),
+            (-1_256, options, -1.26, "k"),
+            (999_999, Options::default(), 1.0, "M"),
+            (2_000, Options::default(), 2.0, "k"),
+        ];
+
+        fixtures.iter().for_each(|(case, options, mantissa, unit)| {
+            let result = abbrev_mantissa(*case, Some(*options));
+            assert_eq!(result, Some((*mantissa, *unit)));
+        });
+
+        let options = Options {
+            abbreviations: Some(&[""]),
+            ..Default::default()
+        };
+
+        assert_eq!(abbrev_mantissa(1_500, Some(options)), None);
+        assert_eq!(
+            abbrev_mantissa(25 * 10_u128.pow(23), None),
+            Some((2.5, "Y"))
+        );
+    }
+}
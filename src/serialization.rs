@@ -0,0 +1,274 @@
+//! Serialization of [Options](crate::Options) fields whose types do not implement
+//! serde traits.
+
+/// Serializes an optional [RoundingStrategy] by its variant name.
+pub(crate) mod rounding_strategy {
+    use crate::RoundingStrategy;
+    use alloc::string::String;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    const VARIANTS: &[&str] = &[
+        "MidpointNearestEven",
+        "MidpointAwayFromZero",
+        "MidpointTowardZero",
+        "ToZero",
+        "AwayFromZero",
+        "ToNegativeInfinity",
+        "ToPositiveInfinity",
+    ];
+
+    #[allow(deprecated)]
+    fn name(strategy: RoundingStrategy) -> &'static str {
+        match strategy {
+            RoundingStrategy::MidpointNearestEven | RoundingStrategy::BankersRounding => {
+                "MidpointNearestEven"
+            }
+            RoundingStrategy::MidpointAwayFromZero | RoundingStrategy::RoundHalfUp => {
+                "MidpointAwayFromZero"
+            }
+            RoundingStrategy::MidpointTowardZero | RoundingStrategy::RoundHalfDown => {
+                "MidpointTowardZero"
+            }
+            RoundingStrategy::ToZero | RoundingStrategy::RoundDown => "ToZero",
+            RoundingStrategy::AwayFromZero | RoundingStrategy::RoundUp => "AwayFromZero",
+            RoundingStrategy::ToNegativeInfinity => "ToNegativeInfinity",
+            RoundingStrategy::ToPositiveInfinity => "ToPositiveInfinity",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<RoundingStrategy> {
+        Some(match name {
+            "MidpointNearestEven" => RoundingStrategy::MidpointNearestEven,
+            "MidpointAwayFromZero" => RoundingStrategy::MidpointAwayFromZero,
+            "MidpointTowardZero" => RoundingStrategy::MidpointTowardZero,
+            "ToZero" => RoundingStrategy::ToZero,
+            "AwayFromZero" => RoundingStrategy::AwayFromZero,
+            "ToNegativeInfinity" => RoundingStrategy::ToNegativeInfinity,
+            "ToPositiveInfinity" => RoundingStrategy::ToPositiveInfinity,
+            _ => return None,
+        })
+    }
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Option<RoundingStrategy>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.map(name).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RoundingStrategy>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(value) => from_name(&value)
+                .map(Some)
+                .ok_or_else(|| Error::unknown_variant(&value, VARIANTS)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Serializes an optional [Decimal] as its string representation.
+pub(crate) mod decimal {
+    use crate::Decimal;
+    use alloc::string::{String, ToString};
+    use core::str::FromStr;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Option<Decimal>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.map(|value| value.to_string()).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Decimal>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(value) => Decimal::from_str(&value).map(Some).map_err(Error::custom),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Serializes an optional `u128` as a number, or as a string above `u64::MAX`, so that
+/// it can also be deserialized through
+/// [OwnedOptions](crate::OwnedOptions), which buffers the fields of its options.
+pub(crate) mod wide {
+    use alloc::string::ToString;
+    use core::fmt;
+    use serde::de::{Error, Visitor};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// A `u128` deserialized from any integer or from its string representation.
+    struct Wide(u128);
+
+    impl<'de> Deserialize<'de> for Wide {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(WideVisitor)
+        }
+    }
+
+    struct WideVisitor;
+
+    impl Visitor<'_> for WideVisitor {
+        type Value = Wide;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a non-negative integer or its string representation")
+        }
+
+        fn visit_u64<E: Error>(self, value: u64) -> Result<Wide, E> {
+            Ok(Wide(value as u128))
+        }
+
+        fn visit_u128<E: Error>(self, value: u128) -> Result<Wide, E> {
+            Ok(Wide(value))
+        }
+
+        fn visit_i64<E: Error>(self, value: i64) -> Result<Wide, E> {
+            u128::try_from(value).map(Wide).map_err(Error::custom)
+        }
+
+        fn visit_i128<E: Error>(self, value: i128) -> Result<Wide, E> {
+            u128::try_from(value).map(Wide).map_err(Error::custom)
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<Wide, E> {
+            value.parse().map(Wide).map_err(Error::custom)
+        }
+    }
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Option<u128>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => match u64::try_from(*value) {
+                Ok(value) => serializer.serialize_some(&value),
+                Err(_) => serializer.serialize_some(&value.to_string()),
+            },
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u128>, D::Error> {
+        Ok(Option::<Wide>::deserialize(deserializer)?.map(|Wide(value)| value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decimal, Options, OwnedOptions, RoundingStrategy};
+
+    #[test]
+    fn can_deserialize_options() {
+        let options: Options =
+            serde_json::from_str(r#"{ "precision": 2, "rounding_strategy": "ToZero" }"#).unwrap();
+
+        assert_eq!(options.precision, Some(2));
+        assert_eq!(options.rounding_strategy, Some(RoundingStrategy::ToZero));
+        assert_eq!(options.abbreviations, None);
+
+        let result = serde_json::from_str::<Options>(r#"{ "rounding_strategy": "Sideways" }"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn can_serialize_options() {
+        let options = Options {
+            precision: Some(3),
+            rounding_strategy: Some(RoundingStrategy::MidpointNearestEven),
+            abbreviations: Some(&["", "k"]),
+            space: true,
+            ..Default::default()
+        };
+        let value = serde_json::to_value(options).unwrap();
+
+        assert_eq!(value["precision"], 3);
+        assert_eq!(value["rounding_strategy"], "MidpointNearestEven");
+        assert_eq!(value["abbreviations"], serde_json::json!(["", "k"]));
+        assert_eq!(value["space"], true);
+    }
+
+    #[test]
+    fn can_serialize_round_threshold() {
+        let options = Options {
+            round_threshold: Some(Decimal::new(6, 1)),
+            ..Default::default()
+        };
+        let value = serde_json::to_value(options).unwrap();
+
+        assert_eq!(value["round_threshold"], "0.6");
+        assert_eq!(serde_json::from_value::<Options>(value).unwrap(), options);
+
+        let result = serde_json::from_str::<Options>(r#"{ "round_threshold": "half" }"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn can_round_trip_borrowed_fields_through_owned_options() {
+        let options = Options {
+            precision: Some(2),
+            abbreviations: Some(&["", "_c1", "_c2"]),
+            small_units: Some(&["m"]),
+            long_words_plural: Some(&["", "thousands"]),
+            unit_scale: Some(&[(1_000, "K"), (100_000, "L")]),
+            precision_by_level: Some(&[0, 1]),
+            prefix: Some("$"),
+            suffix: Some(" USD"),
+            unit_separator: Some("_"),
+            zero_text: Some("none"),
+            fixed_unit: Some("_c1"),
+            saturate_suffix: Some("+"),
+            assume_unit: Some("_c2"),
+            max_magnitude: Some(10_u128.pow(15)),
+            ..Default::default()
+        };
+        let value = serde_json::to_value(options).unwrap();
+        let owned: OwnedOptions = serde_json::from_value(value.clone()).unwrap();
+
+        assert_eq!(
+            owned.abbreviations,
+            Some(vec!["".into(), "_c1".into(), "_c2".into()])
+        );
+        assert_eq!(owned.options.abbreviations, None);
+        owned.with_options(|result| assert_eq!(result, options));
+        assert_eq!(serde_json::to_value(&owned).unwrap(), value);
+
+        let result: OwnedOptions =
+            serde_json::from_value(serde_json::to_value(&owned).unwrap()).unwrap();
+        assert_eq!(result, owned);
+    }
+
+    #[test]
+    fn can_serialize_wide_integers() {
+        let options = Options {
+            max_magnitude: Some(10_u128.pow(25)),
+            saturate_at: Some(999_000),
+            ..Default::default()
+        };
+        let value = serde_json::to_value(options).unwrap();
+
+        assert_eq!(value["max_magnitude"], "10000000000000000000000000");
+        assert_eq!(value["saturate_at"], 999_000);
+        assert_eq!(
+            serde_json::from_value::<Options>(value.clone()).unwrap(),
+            options
+        );
+        assert_eq!(
+            serde_json::from_value::<OwnedOptions>(value)
+                .unwrap()
+                .options,
+            options
+        );
+
+        let result = serde_json::from_str::<Options>(r#"{ "saturate_at": -1 }"#);
+        assert!(result.is_err());
+    }
+}
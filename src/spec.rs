@@ -0,0 +1,519 @@
+//! Parsing of [Options] from a compact format spec.
+
+use crate::{
+    Align, Grouping, Locale, NegativeStyle, Normalization, NotationMode, NumberScale, Options,
+    RoundingStrategy, SignPosition, SpecError,
+};
+use core::str::FromStr;
+
+/// Parses [Options] from a comma-separated format spec, such as `p3,round=zero,space`
+/// for a precision of `3`, rounding towards zero and a space before the unit.
+///
+/// Entries are either flags or `key=value` pairs named after the fields of [Options]
+/// that hold a single value, along with the following shorthands. Lists such as
+/// [Options::abbreviations] cannot be set from a spec.
+///
+/// * `p<n>` - Sets [Options::precision], such as `p2`.
+/// * `round=<mode>` - Sets [Options::rounding_strategy] to one of `even`, `half_up`,
+///   `half_down`, `zero`, `away`, `floor` or `ceil`, which
+///   `negative_rounding_strategy` accepts as well.
+/// * `parentheses` - Sets [Options::negative_style] to [NegativeStyle::Parentheses].
+///
+/// Enum values are written in snake case, such as `sign_position=after_prefix` or
+/// `align=left`.
+///
+/// Text values such as `prefix=$` are borrowed from the spec, so they cannot contain a
+/// comma.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{abbrev_num, Options, RoundingStrategy};
+///
+/// let options = Options::try_from("p3,round=zero,space").unwrap();
+///
+/// assert_eq!(options.precision, Some(3));
+/// assert_eq!(options.rounding_strategy, Some(RoundingStrategy::ToZero));
+/// assert_eq!(abbrev_num(1_234_567, Some(options)), Some("1.234 M".to_string()));
+/// ```
+impl<'a> TryFrom<&'a str> for Options<'a> {
+    type Error = SpecError<'a>;
+
+    fn try_from(spec: &'a str) -> Result<Self, Self::Error> {
+        let mut options = Options::default();
+
+        for entry in spec.split(',').map(str::trim) {
+            match entry.split_once('=') {
+                _ if entry.is_empty() => {}
+                Some((key, value)) => set_value(&mut options, key.trim(), value)?,
+                None => set_flag(&mut options, entry)?,
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// The keys of the entries without a value.
+const FLAGS: &[&str] = &[
+    "space",
+    "long_words",
+    "scientific_fallback",
+    "superscript_exponent",
+    "keep_trailing_zeros",
+    "hide_zero_exponent",
+    "force_sign",
+    "force_sign_zero",
+    "parentheses",
+    "long_bit_rate",
+    "always_decimal",
+    "group_fraction",
+    "nice_numbers",
+    "clamp_to_max_unit",
+    "unit_before",
+    "no_unit_promotion",
+    "clamp_precision",
+];
+
+/// The keys of the entries with a value.
+const VALUES: &[&str] = &[
+    "precision",
+    "min_precision",
+    "max_precision",
+    "significant_digits",
+    "base",
+    "step",
+    "min_abbrev_value",
+    "width",
+    "max_width",
+    "max_magnitude",
+    "mantissa_width",
+    "fill",
+    "decimal_separator",
+    "group_separator",
+    "prefix",
+    "suffix",
+    "unit_separator",
+    "zero_text",
+    "fixed_unit",
+    "round",
+    "rounding_strategy",
+    "negative_rounding_strategy",
+    "negative_style",
+    "sign_position",
+    "align",
+    "notation",
+    "scale",
+    "grouping",
+    "locale",
+    "normalization",
+    "saturate_at",
+    "saturate_suffix",
+    "assume_unit",
+    "base_level_offset",
+    "round_threshold",
+];
+
+/// Applies an entry without a value to the options.
+fn set_flag<'a>(options: &mut Options<'a>, key: &'a str) -> Result<(), SpecError<'a>> {
+    match key {
+        "space" => options.space = true,
+        "long_words" => options.long_words = true,
+        "scientific_fallback" => options.scientific_fallback = true,
+        "superscript_exponent" => options.superscript_exponent = true,
+        "keep_trailing_zeros" => options.keep_trailing_zeros = true,
+        "hide_zero_exponent" => options.hide_zero_exponent = true,
+        "force_sign" => options.force_sign = true,
+        "force_sign_zero" => options.force_sign_zero = true,
+        "parentheses" => options.negative_style = NegativeStyle::Parentheses,
+        "long_bit_rate" => options.long_bit_rate = true,
+        "always_decimal" => options.always_decimal = true,
+        "group_fraction" => options.group_fraction = true,
+        "nice_numbers" => options.nice_numbers = true,
+        "clamp_to_max_unit" => options.clamp_to_max_unit = true,
+        "unit_before" => options.unit_before = true,
+        "no_unit_promotion" => options.no_unit_promotion = true,
+        "clamp_precision" => options.clamp_precision = true,
+        _ if VALUES.contains(&key) => return Err(SpecError::InvalidValue { key, value: "" }),
+        _ => match key.strip_prefix('p') {
+            Some(value) if !value.is_empty() => options.precision = Some(number(key, value)?),
+            _ => return Err(SpecError::UnknownKey { key }),
+        },
+    }
+
+    Ok(())
+}
+
+/// Applies a `key=value` entry to the options.
+fn set_value<'a>(
+    options: &mut Options<'a>,
+    key: &'a str,
+    value: &'a str,
+) -> Result<(), SpecError<'a>> {
+    match key {
+        "precision" => options.precision = Some(number(key, value)?),
+        "min_precision" => options.min_precision = Some(number(key, value)?),
+        "max_precision" => options.max_precision = Some(number(key, value)?),
+        "significant_digits" => options.significant_digits = Some(number(key, value)?),
+        "base" => options.base = Some(number(key, value)?),
+        "step" => options.step = Some(number(key, value)?),
+        "min_abbrev_value" => options.min_abbrev_value = Some(number(key, value)?),
+        "width" => options.width = Some(number(key, value)?),
+        "max_width" => options.max_width = Some(number(key, value)?),
+        "max_magnitude" => options.max_magnitude = Some(number(key, value)?),
+        "mantissa_width" => options.mantissa_width = Some(number(key, value)?),
+        "fill" => options.fill = Some(char(key, value)?),
+        "decimal_separator" => options.decimal_separator = Some(char(key, value)?),
+        "group_separator" => options.group_separator = Some(char(key, value)?),
+        "prefix" => options.prefix = Some(value),
+        "suffix" => options.suffix = Some(value),
+        "unit_separator" => options.unit_separator = Some(value),
+        "zero_text" => options.zero_text = Some(value),
+        "fixed_unit" => options.fixed_unit = Some(value),
+        "round" | "rounding_strategy" => {
+            options.rounding_strategy = Some(rounding_strategy(key, value)?)
+        }
+        "negative_rounding_strategy" => {
+            options.negative_rounding_strategy = Some(rounding_strategy(key, value)?)
+        }
+        "negative_style" => {
+            options.negative_style = match value {
+                "minus" => NegativeStyle::Minus,
+                "parentheses" => NegativeStyle::Parentheses,
+                _ => return Err(SpecError::InvalidValue { key, value }),
+            }
+        }
+        "sign_position" => {
+            options.sign_position = match value {
+                "before_prefix" => SignPosition::BeforePrefix,
+                "after_prefix" => SignPosition::AfterPrefix,
+                "suffix" => SignPosition::Suffix,
+                _ => return Err(SpecError::InvalidValue { key, value }),
+            }
+        }
+        "align" => {
+            options.align = match value {
+                "right" => Align::Right,
+                "left" => Align::Left,
+                _ => return Err(SpecError::InvalidValue { key, value }),
+            }
+        }
+        "notation" => {
+            options.notation = match value {
+                "compact" => NotationMode::Compact,
+                "standard" => NotationMode::Standard,
+                "scientific" => NotationMode::Scientific,
+                "engineering" => NotationMode::Engineering,
+                _ => return Err(SpecError::InvalidValue { key, value }),
+            }
+        }
+        "scale" => {
+            options.scale = match value {
+                "short" => NumberScale::Short,
+                "long" => NumberScale::Long,
+                _ => return Err(SpecError::InvalidValue { key, value }),
+            }
+        }
+        "grouping" => {
+            options.grouping = match value {
+                "thousands" => Grouping::Thousands,
+                "indian" => Grouping::Indian,
+                _ => return Err(SpecError::InvalidValue { key, value }),
+            }
+        }
+        "normalization" => {
+            options.normalization = match value {
+                "trim" => Normalization::Trim,
+                "fixed" => Normalization::Fixed,
+                _ => return Err(SpecError::InvalidValue { key, value }),
+            }
+        }
+        "locale" => match Locale::from_tag(value) {
+            Some(locale) => *options = options.with_locale(locale),
+            None => return Err(SpecError::InvalidValue { key, value }),
+        },
+        "saturate_at" => options.saturate_at = Some(number(key, value)?),
+        "saturate_suffix" => options.saturate_suffix = Some(value),
+        "assume_unit" => options.assume_unit = Some(value),
+        "base_level_offset" => options.base_level_offset = Some(number(key, value)?),
+        "round_threshold" => options.round_threshold = Some(number(key, value)?),
+        _ if FLAGS.contains(&key) => return Err(SpecError::InvalidValue { key, value }),
+        _ => return Err(SpecError::UnknownKey { key }),
+    }
+
+    Ok(())
+}
+
+/// Parses the numeric value of an entry.
+fn number<'a, T: FromStr>(key: &'a str, value: &'a str) -> Result<T, SpecError<'a>> {
+    value
+        .parse()
+        .map_err(|_| SpecError::InvalidValue { key, value })
+}
+
+/// Parses the single character value of an entry.
+fn char<'a>(key: &'a str, value: &'a str) -> Result<char, SpecError<'a>> {
+    let mut chars = value.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(char), None) => Ok(char),
+        _ => Err(SpecError::InvalidValue { key, value }),
+    }
+}
+
+/// Parses the rounding strategy value of an entry.
+fn rounding_strategy<'a>(key: &'a str, value: &'a str) -> Result<RoundingStrategy, SpecError<'a>> {
+    Ok(match value {
+        "even" => RoundingStrategy::MidpointNearestEven,
+        "half_up" => RoundingStrategy::MidpointAwayFromZero,
+        "half_down" => RoundingStrategy::MidpointTowardZero,
+        "zero" => RoundingStrategy::ToZero,
+        "away" => RoundingStrategy::AwayFromZero,
+        "floor" => RoundingStrategy::ToNegativeInfinity,
+        "ceil" => RoundingStrategy::ToPositiveInfinity,
+        _ => return Err(SpecError::InvalidValue { key, value }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{abbrev_num, Decimal};
+
+    #[test]
+    fn can_parse_format_specs() {
+        let options = Options::try_from("p3,round=zero,space").unwrap();
+
+        assert_eq!(
+            options,
+            Options {
+                precision: Some(3),
+                rounding_strategy: Some(RoundingStrategy::ToZero),
+                space: true,
+                ..Default::default()
+            }
+        );
+
+        let options = Options::try_from(" prefix=$ , base=1024,, notation=engineering ").unwrap();
+
+        assert_eq!(options.prefix, Some("$"));
+        assert_eq!(options.base, Some(1024));
+        assert_eq!(options.notation, NotationMode::Engineering);
+        assert_eq!(Options::try_from(""), Ok(Options::default()));
+
+        let options = Options::try_from("parentheses,group_separator=_,p0").unwrap();
+
+        assert_eq!(options.negative_style, NegativeStyle::Parentheses);
+        assert_eq!(
+            abbrev_num(-1_500_000, Some(options)),
+            Some("(2M)".to_string())
+        );
+
+        let options = Options::try_from("locale=de-DE,p2").unwrap();
+
+        assert_eq!(
+            options,
+            Options {
+                precision: Some(2),
+                ..Options::for_locale(Locale::DeDe)
+            }
+        );
+    }
+
+    #[test]
+    fn cannot_parse_invalid_format_specs() {
+        let fixtures: Vec<(&str, SpecError)> = vec![
+            ("colour=red", SpecError::UnknownKey { key: "colour" }),
+            ("p", SpecError::UnknownKey { key: "p" }),
+            (
+                "px",
+                SpecError::InvalidValue {
+                    key: "px",
+                    value: "x",
+                },
+            ),
+            (
+                "round=sideways",
+                SpecError::InvalidValue {
+                    key: "round",
+                    value: "sideways",
+                },
+            ),
+            (
+                "space=yes",
+                SpecError::InvalidValue {
+                    key: "space",
+                    value: "yes",
+                },
+            ),
+            (
+                "precision",
+                SpecError::InvalidValue {
+                    key: "precision",
+                    value: "",
+                },
+            ),
+            (
+                "base_level_offset=1.5",
+                SpecError::InvalidValue {
+                    key: "base_level_offset",
+                    value: "1.5",
+                },
+            ),
+            (
+                "round_threshold=half",
+                SpecError::InvalidValue {
+                    key: "round_threshold",
+                    value: "half",
+                },
+            ),
+            (
+                "fill=ab",
+                SpecError::InvalidValue {
+                    key: "fill",
+                    value: "ab",
+                },
+            ),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            assert_eq!(Options::try_from(*case), Err(*expected));
+        });
+
+        assert_eq!(
+            SpecError::UnknownKey { key: "colour" }.to_string(),
+            "unknown format option `colour`"
+        );
+    }
+
+    #[test]
+    fn can_parse_sign_and_alignment_keys() {
+        let options = Options::try_from(
+            "sign_position=after_prefix,align=left,negative_rounding_strategy=floor,\
+             rounding_strategy=ceil,negative_style=parentheses",
+        )
+        .unwrap();
+
+        assert_eq!(
+            options,
+            Options {
+                sign_position: SignPosition::AfterPrefix,
+                align: Align::Left,
+                negative_rounding_strategy: Some(RoundingStrategy::ToNegativeInfinity),
+                rounding_strategy: Some(RoundingStrategy::ToPositiveInfinity),
+                negative_style: NegativeStyle::Parentheses,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            Options::try_from("sign_position=suffix").map(|options| options.sign_position),
+            Ok(SignPosition::Suffix)
+        );
+        assert_eq!(
+            Options::try_from("align=center"),
+            Err(SpecError::InvalidValue {
+                key: "align",
+                value: "center",
+            })
+        );
+    }
+
+    #[test]
+    fn can_parse_flags() {
+        let fixtures: Vec<(&str, Options)> = vec![
+            (
+                "clamp_to_max_unit",
+                Options {
+                    clamp_to_max_unit: true,
+                    ..Default::default()
+                },
+            ),
+            (
+                "clamp_to_max_unit,space",
+                Options {
+                    clamp_to_max_unit: true,
+                    space: true,
+                    ..Default::default()
+                },
+            ),
+            (
+                "unit_before",
+                Options {
+                    unit_before: true,
+                    ..Default::default()
+                },
+            ),
+            (
+                "no_unit_promotion",
+                Options {
+                    no_unit_promotion: true,
+                    ..Default::default()
+                },
+            ),
+            (
+                "clamp_precision",
+                Options {
+                    clamp_precision: true,
+                    ..Default::default()
+                },
+            ),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            assert_eq!(Options::try_from(*case), Ok(*expected));
+        });
+    }
+
+    #[test]
+    fn can_parse_values() {
+        let fixtures: Vec<(&str, Options)> = vec![
+            (
+                "saturate_at=999000",
+                Options {
+                    saturate_at: Some(999_000),
+                    ..Default::default()
+                },
+            ),
+            (
+                "saturate_at=99,saturate_suffix=++",
+                Options {
+                    saturate_at: Some(99),
+                    saturate_suffix: Some("++"),
+                    ..Default::default()
+                },
+            ),
+            (
+                "assume_unit=M",
+                Options {
+                    assume_unit: Some("M"),
+                    ..Default::default()
+                },
+            ),
+            (
+                "base_level_offset=1",
+                Options {
+                    base_level_offset: Some(1),
+                    ..Default::default()
+                },
+            ),
+            (
+                "base_level_offset=-2",
+                Options {
+                    base_level_offset: Some(-2),
+                    ..Default::default()
+                },
+            ),
+            (
+                "round_threshold=0.6",
+                Options {
+                    round_threshold: Some(Decimal::new(6, 1)),
+                    ..Default::default()
+                },
+            ),
+        ];
+
+        fixtures.iter().for_each(|(case, expected)| {
+            assert_eq!(Options::try_from(*case), Ok(*expected));
+        });
+    }
+}
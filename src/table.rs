@@ -0,0 +1,213 @@
+use crate::{abbrev, base, AbbrevError, Decimal, Integer, Options};
+use alloc::string::String;
+use alloc::vec::Vec;
+use rust_decimal::prelude::FromPrimitive;
+
+/// The divisors of every abbreviation level for a base, computed once and shared by many
+/// abbreviations through [Options::scale_table].
+///
+/// A table only applies to options with the same base, such as those it was created
+/// from. Options with any other base ignore the table and compute their divisors as
+/// usual.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{format_with_table, Options, ScaleTable};
+///
+/// let options = Options::default();
+/// let table = ScaleTable::new(&options).unwrap();
+///
+/// assert_eq!(table.base(), 1_000);
+/// assert_eq!(
+///     format_with_table(1_500_000, Some(options), &table),
+///     Some("1.5M".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaleTable {
+    base: u128,
+    divisors: Vec<u128>,
+    decimals: Vec<Option<Decimal>>,
+}
+
+impl ScaleTable {
+    /// Computes the divisors for the base of the given options, failing with
+    /// [AbbrevError::InvalidBase] if the base is invalid.
+    pub fn new(options: &Options) -> Result<Self, AbbrevError> {
+        let base = base(options)?;
+        let mut divisors = Vec::new();
+        let mut divisor = Some(1_u128);
+
+        while let Some(value) = divisor {
+            divisors.push(value);
+            divisor = value.checked_mul(base);
+        }
+
+        let decimals = divisors
+            .iter()
+            .map(|divisor| Decimal::from_u128(*divisor))
+            .collect();
+
+        Ok(ScaleTable {
+            base,
+            divisors,
+            decimals,
+        })
+    }
+
+    /// Returns the base of the table.
+    pub fn base(&self) -> u128 {
+        self.base
+    }
+
+    /// Returns the divisor of the given level, saturating at `u128::MAX`.
+    pub(crate) fn divisor(&self, level: u32) -> u128 {
+        self.divisors
+            .get(level as usize)
+            .copied()
+            .unwrap_or(u128::MAX)
+    }
+
+    /// Returns the divisor of the given level as a decimal.
+    pub(crate) fn decimal_divisor(&self, level: u32) -> Result<Decimal, AbbrevError> {
+        self.decimals
+            .get(level as usize)
+            .copied()
+            .flatten()
+            .ok_or(AbbrevError::ConversionFailed)
+    }
+
+    /// Returns the level of the largest divisor that does not exceed the non-zero integer.
+    pub(crate) fn level(&self, integer: u128) -> u32 {
+        // The divisors are ascending, so the level is found with a binary search.
+        self.divisors
+            .partition_point(|divisor| *divisor <= integer)
+            .saturating_sub(1) as u32
+    }
+}
+
+/// Abbreviates the given integer using the divisors of a precomputed [ScaleTable].
+///
+/// This behaves exactly like [abbrev](crate::abbrev) with [Options::scale_table] set to
+/// the table.
+///
+/// # Arguments
+///
+/// * `number` - The integer to be abbreviated.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+/// * `table` - The [ScaleTable] for the base of the options.
+///
+/// # Returns
+///
+/// `Some(value)`, a string representation of the abbreviated form of the number.
+/// Returns `None` if the number is out of bounds or cannot be abbreviated using the
+/// provided abbreviations.
+pub fn format_with_table(
+    number: impl Integer,
+    options: Option<Options>,
+    table: &ScaleTable,
+) -> Option<String> {
+    let options = Options {
+        scale_table: Some(table),
+        ..options.unwrap_or_default()
+    };
+
+    abbrev(number, Some(options))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{abbrev_decimal, abbrev_i128, Decimal};
+
+    #[test]
+    fn can_compute_scale_tables() {
+        let table = ScaleTable::new(&Options::default()).unwrap();
+
+        assert_eq!(table.divisor(0), 1);
+        assert_eq!(table.divisor(4), 1_000_000_000_000);
+        assert_eq!(table.divisor(13), u128::MAX);
+        assert_eq!(table.level(999), 0);
+        assert_eq!(table.level(1_000), 1);
+        assert_eq!(table.level(u128::MAX), 12);
+        assert_eq!(table.decimal_divisor(9), Ok(Decimal::from(10_u128.pow(27))));
+        assert_eq!(
+            table.decimal_divisor(10),
+            Err(AbbrevError::ConversionFailed)
+        );
+
+        let options = Options {
+            base: Some(1),
+            ..Default::default()
+        };
+
+        assert_eq!(ScaleTable::new(&options), Err(AbbrevError::InvalidBase));
+    }
+
+    #[test]
+    fn can_abbreviate_using_scale_tables() {
+        let fixtures: Vec<Options> = vec![
+            Options::default(),
+            Options {
+                base: Some(1024),
+                precision: Some(2),
+                ..Default::default()
+            },
+            Options {
+                step: Some(4),
+                min_abbrev_value: Some(100_000),
+                ..Default::default()
+            },
+            Options {
+                fixed_unit: Some("M"),
+                ..Default::default()
+            },
+            Options {
+                clamp_to_max_unit: true,
+                ..Default::default()
+            },
+        ];
+        let numbers: Vec<i128> = vec![0, 999, -1_500, 99_999, 999_999, 1_234_567_890, i128::MIN];
+
+        fixtures.iter().for_each(|options| {
+            let table = ScaleTable::new(options).unwrap();
+
+            numbers.iter().for_each(|number| {
+                let result = format_with_table(*number, Some(*options), &table);
+                assert_eq!(result, abbrev_i128(*number, Some(*options)), "{number}");
+            });
+
+            let options = Options {
+                scale_table: Some(&table),
+                ..*options
+            };
+            let number = Decimal::new(-12_345_678, 1);
+
+            assert_eq!(
+                abbrev_decimal(number, Some(options)),
+                abbrev_decimal(
+                    number,
+                    Some(Options {
+                        scale_table: None,
+                        ..options
+                    })
+                )
+            );
+        });
+    }
+
+    #[test]
+    fn can_ignore_scale_tables_with_other_bases() {
+        let table = ScaleTable::new(&Options::default()).unwrap();
+        let options = Options {
+            base: Some(1024),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            format_with_table(1_536, Some(options), &table),
+            Some("1.5k".to_string())
+        );
+    }
+}
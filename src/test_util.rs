@@ -0,0 +1,238 @@
+use crate::{
+    abbrev_with_scale, level_options, parse_abbrev, unit_level, Options, RoundingStrategy,
+};
+
+/// Asserts that the given number is abbreviated into a string that parses back within the
+/// rounding error of the abbreviation.
+///
+/// The rounding error is one step of the precision or significant digits of the unit,
+/// scaled by the divisor of the unit. Half a step is allowed for midpoint rounding
+/// strategies, and a whole step for directed ones such as
+/// [RoundingStrategy::AwayFromZero] or [Options::round_threshold]. The options must
+/// produce a result that [parse_abbrev](crate::parse_abbrev) understands, so
+/// [Options::width], [Options::zero_text] and full-word units with plural forms are not
+/// supported, and neither is [Options::nice_numbers].
+///
+/// # Arguments
+///
+/// * `number` - The integer to be abbreviated and parsed back.
+/// * `options` - An optional parameter specifying the [Options] for abbreviation.
+///
+/// # Panics
+///
+/// Panics if the number cannot be abbreviated, if the result cannot be parsed, or if the
+/// parsed value is beyond the rounding error.
+///
+/// # Examples
+///
+/// ```
+/// use abbrev_num::{assert_roundtrip, Options};
+///
+/// assert_roundtrip(1_234_567, None);
+/// assert_roundtrip(
+///     -999_999,
+///     Some(Options {
+///         precision: Some(0),
+///         ..Default::default()
+///     }),
+/// );
+/// ```
+pub fn assert_roundtrip(number: isize, options: Option<Options>) {
+    let Some((result, divisor)) = abbrev_with_scale(number, options) else {
+        panic!("{number} could not be abbreviated");
+    };
+
+    assert_parsed_within_rounding_error(number, &result, divisor, options);
+}
+
+/// Asserts that the abbreviation of the number with the given divisor parses back within
+/// the rounding error of the options.
+fn assert_parsed_within_rounding_error(
+    number: isize,
+    result: &str,
+    divisor: u128,
+    options: Option<Options>,
+) {
+    let Some(parsed) = parse_abbrev(result, options) else {
+        panic!("`{result}` could not be parsed back into {number}");
+    };
+
+    let error = (parsed - number as f64).abs();
+    let tolerance = rounding_error(number, divisor, &options.unwrap_or_default())
+        + (number as f64).abs() * f64::EPSILON * 4.0;
+
+    assert!(
+        error <= tolerance,
+        "`{result}` parsed back as {parsed}, which is {error} away from {number} beyond the \
+         rounding error of {tolerance}"
+    );
+}
+
+/// Returns the largest error that rounding the number to the given divisor can cause.
+fn rounding_error(number: isize, divisor: u128, options: &Options) -> f64 {
+    let level = unit_level(number, Some(*options)).map_or(0, |(level, _)| level);
+    let options = level_options(level, options);
+    let divisor = divisor as f64;
+    let places = match options.significant_digits {
+        Some(digits) => {
+            let mantissa = (number as f64).abs() / divisor;
+            let magnitude = if mantissa >= 1.0 {
+                mantissa.log10().floor() as i32
+            } else {
+                0
+            };
+
+            digits.max(1) as i32 - 1 - magnitude
+        }
+        None => options.max_precision.or(options.precision).unwrap_or(1) as i32,
+    };
+    let strategy = match options.negative_rounding_strategy {
+        Some(strategy) if number < 0 => Some(strategy),
+        _ => options.rounding_strategy,
+    };
+    let midpoint = matches!(
+        strategy.unwrap_or(RoundingStrategy::MidpointNearestEven),
+        RoundingStrategy::MidpointNearestEven
+            | RoundingStrategy::MidpointAwayFromZero
+            | RoundingStrategy::MidpointTowardZero
+    ) && options.round_threshold.is_none()
+        && !options.no_unit_promotion;
+    let step = divisor * 10_f64.powi(-places);
+
+    if midpoint {
+        step / 2.0
+    } else {
+        step
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NotationMode, RoundingStrategy};
+
+    #[test]
+    fn can_assert_roundtrips() {
+        let fixtures: Vec<Options> = vec![
+            Options::default(),
+            Options {
+                precision: Some(0),
+                ..Default::default()
+            },
+            Options {
+                significant_digits: Some(1),
+                ..Default::default()
+            },
+            Options {
+                min_precision: Some(3),
+                rounding_strategy: Some(RoundingStrategy::AwayFromZero),
+                ..Default::default()
+            },
+            Options {
+                base: Some(1024),
+                group_separator: Some(' '),
+                decimal_separator: Some(','),
+                ..Default::default()
+            },
+            Options {
+                significant_digits: Some(1),
+                group_separator: Some(','),
+                notation: NotationMode::Standard,
+                ..Default::default()
+            },
+            Options {
+                fixed_unit: Some("M"),
+                ..Default::default()
+            },
+            Options {
+                notation: NotationMode::Engineering,
+                prefix: Some("$"),
+                ..Default::default()
+            },
+        ];
+        let numbers: Vec<isize> = vec![0, 7, -15, 999, 1_049, -99_960, 1_234_567, 45_000_000];
+
+        fixtures.iter().for_each(|options| {
+            numbers.iter().for_each(|number| {
+                assert_roundtrip(*number, Some(*options));
+            });
+        });
+    }
+
+    #[test]
+    fn can_derive_rounding_error_from_options() {
+        let fixtures: Vec<(isize, u128, Options, f64)> = vec![
+            (1_900, 1_000, Options::default(), 50.0),
+            (
+                1_900,
+                1_000,
+                Options {
+                    precision: Some(2),
+                    ..Default::default()
+                },
+                5.0,
+            ),
+            (
+                1_900,
+                1_000,
+                Options {
+                    rounding_strategy: Some(RoundingStrategy::AwayFromZero),
+                    ..Default::default()
+                },
+                100.0,
+            ),
+            (
+                1_234_567,
+                1,
+                Options {
+                    significant_digits: Some(2),
+                    notation: NotationMode::Standard,
+                    ..Default::default()
+                },
+                50_000.0,
+            ),
+            (
+                1_500_000,
+                1_000_000,
+                Options {
+                    precision_by_level: Some(&[0, 0, 3]),
+                    ..Default::default()
+                },
+                500.0,
+            ),
+        ];
+
+        fixtures
+            .iter()
+            .for_each(|(number, divisor, options, expected)| {
+                assert_eq!(rounding_error(*number, *divisor, options), *expected);
+            });
+    }
+
+    #[test]
+    #[should_panic(expected = "beyond the rounding error of 50")]
+    fn cannot_assert_results_off_by_one_digit() {
+        assert_parsed_within_rounding_error(
+            1_900,
+            "1k",
+            1_000,
+            Some(Options {
+                precision: Some(1),
+                ..Default::default()
+            }),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "could not be parsed back")]
+    fn cannot_assert_unparseable_roundtrips() {
+        assert_roundtrip(
+            1_500,
+            Some(Options {
+                width: Some(8),
+                fill: Some('*'),
+                ..Default::default()
+            }),
+        );
+    }
+}